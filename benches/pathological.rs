@@ -0,0 +1,111 @@
+//! Pathological inputs known to cause quadratic blow-ups in Markdown parsers
+//!
+//! Every input is parsed at a base size and at [SCALE] times that size, the run fails when the
+//! parse time grows noticeably faster than the input.
+
+use std::hint::black_box;
+use std::process;
+use std::time::{Duration, Instant};
+
+use mark_oxide::parser::options::ParseOptions;
+use mark_oxide::parser::Parser;
+
+const BASE: usize = 10_000;
+const SCALE: usize = 8;
+const TOLERANCE: f64 = 2.5;
+const RUNS: usize = 5;
+
+/// Name of a case and the input of a given size
+type Case = (&'static str, fn(usize) -> String);
+
+const CASES: &[Case] = &[
+    ("emphasis openers", |n| "*a ".repeat(n)),
+    ("emphasis closers", |n| "a* ".repeat(n)),
+    ("link openers", |n| "[a".repeat(n)),
+    ("link closers", |n| "a]".repeat(n)),
+    ("backtick runs", |n| (1..n / 10).map(|i| "`".repeat(i % 50) + "a").collect()),
+    ("atx closing sequences", |n| format!("# a{}\n", " #".repeat(n))),
+    ("thematic break", |n| format!("{}\n", "* ".repeat(n))),
+    ("unclosed fences", |n| format!("~~~~\n{}", "~~~\n".repeat(n))),
+    ("setext candidates", |n| "a\n".repeat(n)),
+    ("table candidate lines", |n| format!("{}\n", "| a ".repeat(n / 4))),
+    ("colon fence candidates", |n| format!("{}\n", ":".repeat(n))),
+    ("candidate prefixes", |n| "*[^:\n".repeat(n / 5)),
+    ("indented lines", |n| "    a\n\n".repeat(n)),
+    ("backslash escapes", |n| "\\*".repeat(n)),
+    ("unclosed html", |n| "<a ".repeat(n)),
+    ("inline emphasis", |n| format!("# {}\n", "*a _b ".repeat(n))),
+    ("inline code spans", |n| format!("# {}\n", (1..n / 10).map(|i| "`".repeat(i % 50) + "a").collect::<String>())),
+    ("inline strikethrough", |n| format!("# {}\n", "~~a ~b ".repeat(n))),
+    ("url autolinks", |n| format!("# {}\n", "www.a.b)) ".repeat(n))),
+    ("email autolinks", |n| format!("# {}\n", "a.b@c.d@".repeat(n))),
+    ("inline html", |n| format!("# {}\n", "<!-- <? <a b='c' ".repeat(n))),
+    ("footnote references", |n| format!("# {}\n\n[^a]: b\n", "[^a] [^".repeat(n))),
+    ("footnote definitions", |n| (0..n / 10).map(|i| format!("# [^{i}]\n\n[^{i}]: a\n\n")).collect()),
+    ("math openers", |n| format!("# {}\n", "$a $$b \\$".repeat(n))),
+    ("heading attributes", |n| format!("# a{}\n", " {#a".repeat(n))),
+    ("definition groups", |n| "a\n: b\n\n".repeat(n / 2)),
+    ("nested admonitions", |n| "::: a\n> [!NOTE]\n".repeat(n / 20)),
+    ("unclosed directive labels", |n| format!("# {}", ":a[".repeat(n / 3))),
+    ("unclosed wiki links", |n| format!("# {}", "[[a ".repeat(n / 4))),
+    ("smart quote openers", |n| format!("# {}", " 'a \"b".repeat(n / 6))),
+    ("unknown emoji shortcodes", |n| format!("# {}", ":abcdefghijklmnopqrstuvwxyz".repeat(n / 27))),
+    ("unmatched inline extension openers", |n| format!("# {}", "a^ ==b ++c ~d ".repeat(n / 14))),
+    ("abbreviation lengths", |n| {
+        let definitions = (1..200).map(|length| format!("*[{}]: t\n", "a".repeat(length))).collect::<String>();
+        format!("{definitions}\n# {}\n", "a ".repeat(n / 2))
+    }),
+];
+
+/// GitHub Flavored Markdown with every optional extension and smart punctuation enabled
+fn options() -> ParseOptions {
+    let mut options = ParseOptions::gfm();
+    options.constructs.math_text = true;
+    options.constructs.math_flow = true;
+    options.constructs.attributes = true;
+    options.constructs.definition_list = true;
+    options.constructs.admonition = true;
+    options.constructs.directive = true;
+    options.constructs.wiki_link = true;
+    options.constructs.emoji = true;
+    options.constructs.superscript = true;
+    options.constructs.subscript = true;
+    options.constructs.mark = true;
+    options.constructs.inserted = true;
+    options.constructs.abbreviation = true;
+    options.smart_punctuation = true;
+    options
+}
+
+fn measure(input: &str) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(Parser::from_reader_with_options(input.as_bytes(), options()).parse_to_string().ok());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    let mut failed = false;
+
+    for (name, generate) in CASES {
+        let small = measure(&generate(BASE));
+        let large = measure(&generate(BASE * SCALE));
+        let ratio = large.as_secs_f64() / small.as_secs_f64().max(f64::EPSILON);
+        let linear = ratio <= SCALE as f64 * TOLERANCE;
+
+        println!(
+            "{name:<24} {small:>12.2?} {large:>12.2?} x{ratio:<6.1} {}",
+            if linear { "ok" } else { "SUPERLINEAR" },
+        );
+
+        failed |= !linear;
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -1,4 +1,7 @@
+#[derive(Debug)]
 pub enum Error {
     StartState,
     Conversion,
+    HeadingLevel(usize),
+    ListStart(usize),
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
-mod parser;
+pub mod parser;
 mod unicode;
-mod error;
+pub mod error;
 
 #[cfg(test)]
 mod tests {
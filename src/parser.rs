@@ -2,26 +2,43 @@ use std::io;
 use std::io::Read;
 
 use crate::parser::character_parser::CharacterParser;
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
 use crate::parser::document::Document;
+use crate::parser::options::{ParseOptions, RenderOptions};
 
 mod state;
 mod character;
-mod document;
+pub mod document;
+pub mod options;
 mod character_parser;
 mod transition;
 mod line_ending;
 mod effect;
+pub(crate) mod inline;
+mod raw_html;
+mod directive;
+mod front_matter;
+
+/// Byte order mark at the start of the input, dropped before parsing
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
 
 pub struct Parser<R> {
     reader: R,
     character_parser: CharacterParser,
+    options: ParseOptions,
 }
 
 impl<R: Sized + Read> Parser<R> {
     pub fn from_reader(reader: R) -> Self {
+        Self::from_reader_with_options(reader, ParseOptions::default())
+    }
+
+    pub fn from_reader_with_options(reader: R, options: ParseOptions) -> Self {
         Self {
             reader,
-            character_parser: CharacterParser::new(),
+            character_parser: CharacterParser::new(options.clone()),
+            options,
         }
     }
 
@@ -29,15 +46,50 @@ impl<R: Sized + Read> Parser<R> {
         let mut data = String::new();
         self.reader.read_to_string(&mut data)?;
 
-        for char in data.chars() {
+        let source = data.strip_prefix(BYTE_ORDER_MARK).unwrap_or(&data);
+
+        let (front_matter, data) = match self.options.constructs.front_matter {
+            true => front_matter::split(source)
+                .map_or((None, source), |(front_matter, rest)| (Some(front_matter), rest)),
+            false => (None, source),
+        };
+        // Lines are counted from the start of the source, front matter included
+        self.character_parser.skip_lines(source[..source.len() - data.len()].matches('\n').count());
+
+        let mut size = data.len().min(self.options.limits.max_document_size);
+        while !data.is_char_boundary(size) {
+            size -= 1;
+        }
+        let (content, overflow) = data.split_at(size);
+
+        for char in content.chars() {
             self.character_parser.parse_character(char);
         }
 
-        Ok(self.character_parser.end_document())
+        let mut document = self.character_parser.end_document();
+        inline::parse_document(&mut document, &self.options);
+        document.set_front_matter(front_matter);
+
+        if self.options.heading_ids {
+            document.assign_heading_ids();
+        }
+
+        if !overflow.is_empty() {
+            document.push(Leaf::Paragraph {
+                content: vec![Inline::Text(overflow.to_string())],
+            }.into());
+        }
+
+        Ok(document)
     }
 
     pub fn parse_to_string(self) -> io::Result<String> {
         self.parse()
             .map(Document::to_string)
     }
+
+    pub fn parse_to_string_with(self, options: &RenderOptions) -> io::Result<String> {
+        self.parse()
+            .map(|document| document.to_html(options))
+    }
 }
\ No newline at end of file
@@ -1,10 +1,18 @@
+use std::mem;
+
 use crate::parser::character_parser::carriage_return::CarriageReturnState;
 use crate::parser::character_parser::character_transition::{CharacterSubTransition, CharacterTransitionHandler, CharacterTransitionResult, PositionedLineEnding};
 use crate::parser::character_parser::default::DefaultState;
 use crate::parser::character_parser::potential_character_reference::PotentialCharacterReferenceState;
 use crate::parser::character_parser::potential_escape::PotentialEscapeState;
 use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::visitor;
+use crate::parser::document::visitor::VisitorMut;
 use crate::parser::document::Document;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::StateHandler;
 use crate::parser::transition::{Transition, TransitionEffect};
 
@@ -14,65 +22,175 @@ mod carriage_return;
 mod potential_character_reference;
 mod character_transition;
 
+/// Blocks of a nested source, such as the content of a container
+///
+/// Source lines are relative to the nested source, the parser of the enclosing source shifts them
+/// to its own lines once it locates the container.
+pub(crate) fn parse_blocks(source: &str, options: ParseOptions) -> Vec<Block> {
+    let mut parser = CharacterParser::new(options);
+
+    for character in source.chars() {
+        parser.parse_character(character);
+    }
+
+    parser.end_document()
+        .into_blocks()
+}
+
+/// # Shifted
+/// Moves the source positions of headings down by a number of lines
+struct Shifted(usize);
+
+impl VisitorMut for Shifted {
+    fn fold_leaf(&mut self, mut leaf: Leaf) -> Vec<Block> {
+        if let Leaf::AtxHeading { meta, .. } | Leaf::SetextHeading { meta, .. } = &mut leaf {
+            if let Some(position) = &mut meta.position {
+                position.start_line += self.0;
+                position.end_line += self.0;
+            }
+        }
+
+        vec![leaf.into()]
+    }
+}
+
 pub struct CharacterParser {
     document: Document,
     state: StateHandler,
     internal_state_handler: CharParserStateHandler,
+    lines: Lines,
+}
+
+/// # Lines
+/// Current line of the source, locating the blocks completed on it
+struct Lines {
+    current: usize,
+    has_content: bool,
+    /// Line the running block started on
+    block_start: Option<usize>,
+}
+
+impl Lines {
+    /// Last line of a block completed by a character, the character starts the line following the
+    /// block
+    fn before_character(&self) -> usize {
+        self.current.saturating_sub(1).max(1)
+    }
+
+    /// Last line of a block completed at the end of the document
+    fn at_end(&self) -> usize {
+        match self.has_content {
+            true => self.current,
+            false => self.before_character(),
+        }
+    }
 }
 
 impl CharacterParser {
-    pub fn new() -> Self {
+    pub fn new(options: ParseOptions) -> Self {
         Self {
             document: Document::new(),
-            state: Default::default(),
+            state: StateHandler::from(options),
             internal_state_handler: CharParserStateHandler(Default::default()),
+            lines: Lines {
+                current: 1,
+                has_content: false,
+                block_start: None,
+            },
         }
     }
 
+    /// Skips the lines of the source consumed before parsing, such as a front matter
+    pub fn skip_lines(&mut self, count: usize) {
+        self.lines.current += count;
+    }
+
     pub fn parse_character(&mut self, character: char) {
         let result = self.internal_state_handler.transition(character);
 
-        Self::handle_transition_result(result, &mut self.state, &mut self.document);
+        Self::handle_transition_result(result, &mut self.state, &mut self.document, &mut self.lines);
     }
 
     pub fn end_document(mut self) -> Document {
         let result = self.internal_state_handler.end();
 
-        Self::handle_transition_result(result, &mut self.state, &mut self.document);
-        Self::handle_result(&mut self.document, self.state.end());
+        Self::handle_transition_result(result, &mut self.state, &mut self.document, &mut self.lines);
+        // The end of the document ends its last line, as a line ending does
+        if self.lines.has_content {
+            let (_, block) = self.state.end_line(LineEnding::LineFeed).content();
+            let end_line = self.lines.current;
+            Self::handle_result(&mut self.document, block, &self.state, &mut self.lines, end_line);
+            Self::next_line(&mut self.lines);
+        }
+        let block = self.state.end();
+        let end_line = self.lines.at_end();
+        Self::handle_result(&mut self.document, block, &self.state, &mut self.lines, end_line);
 
         self.document
     }
 
-    fn handle_transition_result(result: CharacterTransitionResult, state: &mut StateHandler, document: &mut Document) {
+    fn handle_transition_result(result: CharacterTransitionResult, state: &mut StateHandler, document: &mut Document, lines: &mut Lines) {
         if let Some(PositionedLineEnding::Before(line_ending)) = result.line_ending {
             let (_, block) = state.end_line(line_ending).content();
-            Self::handle_result(
-                document,
-                block,
-            );
+            let end_line = lines.current;
+            Self::handle_result(document, block, state, lines, end_line);
+            Self::next_line(lines);
         }
 
         for character in result.characters {
+            lines.has_content = true;
             let (_, block) = state.transition(character).content();
-            Self::handle_result(
-                document,
-                block
-            );
+            let end_line = lines.before_character();
+            Self::handle_result(document, block, state, lines, end_line);
         }
 
         if let Some(PositionedLineEnding::After(line_ending)) = result.line_ending {
             let (_, block) = state.end_line(line_ending).content();
-            Self::handle_result(
-                document,
-                block,
-            );
+            let end_line = lines.current;
+            Self::handle_result(document, block, state, lines, end_line);
+            Self::next_line(lines);
         }
     }
 
-    fn handle_result(document: &mut Document, block: Option<Block>) {
-        if let Some(block) = block {
-            document.push(block);
+    fn next_line(lines: &mut Lines) {
+        lines.current += 1;
+        lines.has_content = false;
+    }
+
+    /// Adds a completed block to the document, a definition list directly following a definition
+    /// list continues it
+    ///
+    /// The content of a container starts on the line following the line it started on, the
+    /// positions within it are shifted accordingly.
+    fn handle_result(document: &mut Document, block: Option<Block>, state: &StateHandler, lines: &mut Lines, end_line: usize) {
+        let start_line = lines.block_start;
+        lines.block_start = match (state.in_block(), block.is_some()) {
+            (false, _) => None,
+            (true, true) => Some(lines.current),
+            (true, false) => start_line.or(Some(lines.current)),
+        };
+
+        let Some(mut block) = block else {
+            return;
+        };
+
+        match &mut block {
+            Block::Leaf(leaf) => leaf.locate(end_line),
+            Block::Container(container) => {
+                let content = mem::take(&mut container.content);
+                container.content = visitor::fold_blocks(&mut Shifted(start_line.unwrap_or(end_line)), content);
+            }
+        }
+
+        match (document.blocks_mut().last_mut(), block) {
+            (
+                Some(Block::Container(Container { kind: ContainerKind::DefinitionList { tight }, content })),
+                Block::Container(Container { kind: ContainerKind::DefinitionList { tight: continued_tight }, content: continued }),
+            ) => {
+                *tight &= continued_tight;
+                content.extend(continued);
+            }
+            (_, block) => document.push(block),
         }
     }
 }
@@ -0,0 +1,217 @@
+//! Recognition of the name, label and attributes of generic directives
+
+use crate::parser::document::attributes::Attributes;
+use crate::parser::document::directive::{Directive, DirectiveKind};
+use crate::unicode;
+
+pub const COLON: char = ':';
+
+const OPENING_BRACKET: char = '[';
+const CLOSING_BRACKET: char = ']';
+const OPENING_BRACE: char = '{';
+const CLOSING_BRACE: char = '}';
+const QUOTE: char = '"';
+
+/// Directive whose name starts at `start`, following its colons, and the position following it
+///
+/// The name starts with an ASCII letter followed by ASCII letters, digits, `-` or `_`. The label
+/// in brackets may contain balanced brackets, the attributes in braces follow the syntax of
+/// [Attributes::parse]. Neither spans lines or exceeds `max_length` characters.
+pub fn parse(kind: DirectiveKind, source: &[char], start: usize, max_length: usize) -> Option<(Directive, usize)> {
+    let name_length = source[start..].iter()
+        .enumerate()
+        .take_while(|(index, character)| match index {
+            0 => character.is_ascii_alphabetic(),
+            _ => character.is_ascii_alphanumeric() || **character == '-' || **character == '_',
+        })
+        .count();
+
+    if name_length == 0 {
+        return None;
+    }
+
+    let mut position = start + name_length;
+    let name = source[start..position].iter().collect();
+
+    let label = match source.get(position) {
+        Some(&OPENING_BRACKET) => {
+            let end = label_end(source, position + 1, max_length)?;
+            let label = source[position + 1..end].iter().collect();
+            position = end + 1;
+            Some(label)
+        }
+        _ => None,
+    };
+
+    let attributes = match source.get(position) {
+        Some(&OPENING_BRACE) => {
+            let end = attributes_end(source, position + 1, max_length)?;
+            let attributes = Attributes::parse(&source[position..=end].iter().collect::<String>())?;
+            position = end + 1;
+            attributes
+        }
+        _ => Attributes::default(),
+    };
+
+    Some((Directive { kind, name, label, attributes }, position))
+}
+
+/// Position of the bracket closing a label starting at `start`
+fn label_end(source: &[char], start: usize, max_length: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut position = start;
+
+    while position - start <= max_length {
+        match *source.get(position)? {
+            unicode::BACKSLASH => position += 1,
+            unicode::LINE_FEED => return None,
+            OPENING_BRACKET => depth += 1,
+            CLOSING_BRACKET if depth == 0 => return Some(position),
+            CLOSING_BRACKET => depth -= 1,
+            _ => {}
+        }
+        position += 1;
+    }
+
+    None
+}
+
+/// Position of the brace closing attributes starting at `start`, braces within quotes do not close
+fn attributes_end(source: &[char], start: usize, max_length: usize) -> Option<usize> {
+    let mut quoted = false;
+
+    for (position, character) in source.iter().enumerate().skip(start).take(max_length + 1) {
+        match *character {
+            unicode::LINE_FEED => return None,
+            QUOTE => quoted = !quoted,
+            CLOSING_BRACE if !quoted => return Some(position),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::container::{Container, ContainerKind};
+    use crate::parser::document::directive::{Directive, DirectiveKind, UnhandledDirectives};
+    use crate::parser::document::inline::Inline;
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    #[test]
+    /// Directives (extension)
+    fn test_directive_text() {
+        let parser = Parser::from_reader_with_options("# Read :abbr[HTML]{title=\"Hypertext\"} at 10:30\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.directive = true));
+        let document = parser.parse().unwrap();
+
+        match document.blocks() {
+            [Block::Leaf(Leaf::AtxHeading { content, .. })] => {
+                let Inline::Directive(directive) = &content[1] else {
+                    panic!("unexpected inlines {content:?}");
+                };
+
+                assert_eq!(DirectiveKind::Text, directive.kind);
+                assert_eq!("abbr", directive.name);
+                assert_eq!(Some("HTML"), directive.label.as_deref());
+                assert_eq!(vec![("title".to_string(), "Hypertext".to_string())], directive.attributes.pairs);
+            }
+            blocks => panic!("unexpected blocks {blocks:?}"),
+        }
+
+        assert_eq!("<h1>Read HTML at 10:30</h1>\n", document.to_html(&RenderOptions::default()));
+    }
+
+    #[test]
+    /// Directives (extension): leaf directive handlers
+    fn test_directive_leaf_handler() {
+        let parser = Parser::from_reader_with_options("::youtube[A <video>]{#intro .wide}\n\n::other[Text]\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.directive = true));
+        let document = parser.parse().unwrap();
+
+        let mut options = RenderOptions::default();
+        options.directive_handlers.register("youtube", |directive: &Directive, label: &str| {
+            format!("<iframe title=\"{label}\"{}></iframe>\n", directive.attributes.to_html())
+        });
+
+        assert_eq!(
+            "<iframe title=\"A &lt;video&gt;\" id=\"intro\" class=\"wide\"></iframe>\n<p>Text</p>\n",
+            document.to_html(&options)
+        );
+    }
+
+    #[test]
+    /// Directives (extension): container directives
+    fn test_directive_container() {
+        let options = ParseOptions::gfm().with_constructs(|constructs| {
+            constructs.directive = true;
+            constructs.admonition = true;
+        });
+
+        let parser = Parser::from_reader_with_options(":::warning[Careful]\n# Inside\n\n:::note\n## Nested\n:::\n:::\n\n::: tip\n# Tip\n:::\n".as_bytes(), options);
+        let document = parser.parse().unwrap();
+
+        match document.blocks() {
+            [Block::Container(Container { kind: ContainerKind::Directive(directive), content }), Block::Container(Container { kind: ContainerKind::Admonition { .. }, .. })] => {
+                assert_eq!(DirectiveKind::Container, directive.kind);
+                assert_eq!("warning", directive.name);
+                assert_eq!(2, content.len());
+            }
+            blocks => panic!("unexpected blocks {blocks:?}"),
+        }
+
+        let mut options = RenderOptions::default();
+        options.directive_handlers.register("warning", |directive: &Directive, content: &str| {
+            format!("<aside title=\"{}\">\n{content}</aside>\n", directive.label.as_deref().unwrap_or_default())
+        });
+
+        assert_eq!(
+            "<aside title=\"Careful\">\n<h1>Inside</h1>\n<h2>Nested</h2>\n</aside>\n\
+            <div class=\"markdown-alert markdown-alert-tip\">\n<p class=\"markdown-alert-title\">Tip</p>\n<h1>Tip</h1>\n</div>\n",
+            document.to_html(&options)
+        );
+    }
+
+    #[test]
+    /// Directives (extension): unhandled directives without a label render their source
+    fn test_directive_unhandled_source() {
+        let parser = Parser::from_reader_with_options("::video{src=\"x\"}\n\n# A :icon{.star}\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.directive = true));
+        assert_eq!(
+            "<p>::video{src=&quot;x&quot;}</p>\n<h1>A :icon{.star}</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Directives (extension): colon fences within a code fence neither close nor open a container
+    fn test_directive_container_code_fence() {
+        let parser = Parser::from_reader_with_options(":::box\n```\n:::\n```\n:::\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.directive = true));
+        assert_eq!("<pre><code>:::\n</code></pre>\n<h1>After</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Directives (extension): unhandled directives dropped
+    fn test_directive_unhandled_drop() {
+        let parser = Parser::from_reader_with_options("# Text :icon[star]\n\n::video[Clip]\n\n:::box\n# Inside\n:::\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.directive = true));
+        let document = parser.parse().unwrap();
+
+        let options = RenderOptions {
+            unhandled_directives: UnhandledDirectives::Drop,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!("<h1>Text </h1>\n", document.to_html(&options));
+    }
+
+    #[test]
+    /// Directives (extension): invalid names and attributes or disabled
+    fn test_directive_not_recognized() {
+        let parser = Parser::from_reader_with_options("# a:b :1st ::x :name{=}\n\n::name{=}\n\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.directive = true));
+        assert_eq!("<h1>a:b :1st ::x :name{=}</h1>\n<h1>After</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+
+        let parser = Parser::from_reader_with_options("# :abbr[HTML]\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>:abbr[HTML]</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
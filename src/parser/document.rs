@@ -1,30 +1,160 @@
+use std::mem;
+
 use crate::parser::document::block::Block;
+use crate::parser::document::builder::Builder;
+use crate::parser::document::front_matter::FrontMatter;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::slug::Slugger;
+use crate::parser::document::toc::TableOfContents;
+use crate::parser::document::visitor::{Visitor, VisitorMut};
+use crate::parser::options::{RenderOptions, TocOptions};
 
+pub mod attributes;
 pub mod block;
+pub mod builder;
+pub mod code_block;
+pub mod code_info;
+pub mod container;
+pub mod directive;
+pub mod front_matter;
+pub mod heading;
+pub mod highlight;
+pub mod html;
+pub mod inline;
 pub mod leaf;
+pub mod position;
+pub mod registry;
+pub mod shared;
+pub mod slug;
+pub mod toc;
+pub mod tree;
+pub mod visitor;
+pub mod wiki_link;
 
 pub struct Document {
     content: Vec<Block>,
+    front_matter: Option<FrontMatter>,
 }
 
 impl Document {
     pub fn new() -> Self {
         Self {
             content: Vec::new(),
+            front_matter: None,
         }
     }
 
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
     pub fn push(&mut self, block: Block) {
         self.content.push(block);
     }
 
-    pub fn to_string(self) -> String {
+    pub fn blocks(&self) -> &[Block] {
+        &self.content
+    }
+
+    pub fn blocks_mut(&mut self) -> &mut Vec<Block> {
+        &mut self.content
+    }
+
+    /// Front matter of the document, never rendered
+    pub fn front_matter(&self) -> Option<&FrontMatter> {
+        self.front_matter.as_ref()
+    }
+
+    pub fn set_front_matter(&mut self, front_matter: Option<FrontMatter>) {
+        self.front_matter = front_matter;
+    }
+
+    /// Assigns a unique id derived from its text to every heading without one, in document order
+    ///
+    /// Ids already present, such as ids of attribute blocks, are kept and never generated again.
+    pub fn assign_heading_ids(&mut self) {
+        let mut heading_ids = HeadingIds {
+            slugger: Slugger::new(),
+        };
+
+        self.walk(&mut heading_ids);
+        self.walk_mut(&mut heading_ids);
+    }
+
+    pub fn table_of_contents(&self, options: &TocOptions) -> TableOfContents {
+        TableOfContents::new(self, options)
+    }
+
+    pub fn into_blocks(self) -> Vec<Block> {
+        self.content
+    }
+
+    pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.enter_document(self);
+
+        for block in &self.content {
+            visitor::walk_block(visitor, block);
+        }
+
+        visitor.exit_document(self);
+    }
+
+    pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.enter_document(self);
+
+        let content = mem::take(&mut self.content);
+        self.content = visitor::fold_blocks(visitor, content);
+
+        visitor.exit_document(self);
+    }
+
+    /// Renders the blocks in order, replacing every [Leaf::TableOfContents] placeholder by the
+    /// table of contents of the document
+    pub fn to_html(&self, options: &RenderOptions) -> String {
         let mut out = String::new();
+        let mut table_of_contents = None;
 
-        for block in self.content {
-            out.extend(block.to_html().chars());
+        for block in &self.content {
+            match block {
+                Block::Leaf(Leaf::TableOfContents) => out.push_str(table_of_contents.get_or_insert_with(|| {
+                    self.table_of_contents(&options.table_of_contents).to_html()
+                })),
+                block => out.push_str(&block.to_html(options)),
+            }
         }
 
         out
     }
-}
\ No newline at end of file
+
+    pub fn to_string(self) -> String {
+        self.to_html(&RenderOptions::default())
+    }
+}
+
+/// # Heading ids
+/// Assigns ids to the headings of a document
+struct HeadingIds {
+    slugger: Slugger,
+}
+
+impl Visitor for HeadingIds {
+    fn enter_leaf(&mut self, leaf: &Leaf) {
+        if let Leaf::AtxHeading { meta, .. } | Leaf::SetextHeading { meta, .. } = leaf {
+            if let Some(id) = &meta.id {
+                self.slugger.reserve(id);
+            }
+        }
+    }
+}
+
+impl VisitorMut for HeadingIds {
+    fn fold_leaf(&mut self, mut leaf: Leaf) -> Vec<Block> {
+        if let Leaf::AtxHeading { content, meta, .. } | Leaf::SetextHeading { content, meta, .. } = &mut leaf {
+            if meta.id.is_none() {
+                meta.id = Some(self.slugger.slug(&inline::plain_text(content)));
+            }
+        }
+
+        vec![leaf.into()]
+    }
+}
@@ -0,0 +1,241 @@
+use crate::parser::document::html;
+
+/// # Attributes
+/// Identifier, classes and key-value pairs of a `{#id .class key=value}` attribute block
+///
+/// Rendered as HTML attributes in the order `id`, `class` and the pairs as written.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Attributes {
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub pairs: Vec<(String, String)>,
+}
+
+impl Attributes {
+    pub fn is_empty(&self) -> bool {
+        self.id.is_none() && self.classes.is_empty() && self.pairs.is_empty()
+    }
+
+    /// Parses an attribute block including its braces
+    ///
+    /// The block consists of whitespace separated `#id`, `.class` and `key=value` entries, values
+    /// may be quoted with double quotes. A later identifier replaces an earlier one. Any other
+    /// entry makes the block invalid. Event handler attributes like `onclick` are dropped, so that
+    /// no script can be attached to the rendered elements.
+    pub fn parse(block: &str) -> Option<Self> {
+        let mut rest = block.strip_prefix('{')?.strip_suffix('}')?.trim_start();
+        let mut attributes = Self::default();
+
+        while !rest.is_empty() {
+            let end = match rest.strip_prefix(['#', '.']) {
+                Some(_) => rest.find(char::is_whitespace).unwrap_or(rest.len()),
+                None => {
+                    let (key, value) = rest.split_once('=')?;
+                    if !is_key(key) {
+                        return None;
+                    }
+
+                    let (value, length) = match value.strip_prefix('"') {
+                        Some(quoted) => {
+                            let length = quoted.find('"')?;
+                            (&quoted[..length], length + 2)
+                        }
+                        None => {
+                            let length = value.find(char::is_whitespace).unwrap_or(value.len());
+                            (&value[..length], length)
+                        }
+                    };
+
+                    if !is_event_handler(key) {
+                        attributes.pairs.push((key.to_string(), value.to_string()));
+                    }
+                    rest = &rest[key.len() + 1 + length..];
+                    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+                        return None;
+                    }
+
+                    rest = rest.trim_start();
+                    continue;
+                }
+            };
+
+            let (entry, name) = rest[..end].split_at(1);
+            if name.is_empty() || name.contains(['{', '}', '"', '=']) {
+                return None;
+            }
+
+            match entry {
+                "#" => attributes.id = Some(name.to_string()),
+                _ => attributes.classes.push(name.to_string()),
+            }
+            rest = rest[end..].trim_start();
+        }
+
+        Some(attributes)
+    }
+
+    /// Splits a trailing attribute block off `text`
+    ///
+    /// Returns the text preceding the block, without trailing whitespace, and the attributes.
+    /// Text without a valid block at its very end yields [None], as does a block with an escaped
+    /// brace like `\{#id}`.
+    pub fn split_trailing(text: &str) -> Option<(&str, Self)> {
+        let text = text.trim_end();
+        if !text.ends_with('}') || is_escaped(text, text.len() - 1) {
+            return None;
+        }
+
+        let start = text.rfind('{')?;
+        if is_escaped(text, start) {
+            return None;
+        }
+
+        let attributes = Self::parse(&text[start..])?;
+
+        Some((text[..start].trim_end(), attributes))
+    }
+
+    /// Attribute block in the syntax of [Attributes::parse], values quoted, empty attributes
+    /// yield an empty string
+    pub fn to_source(&self) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        let entries = self.id.iter()
+            .map(|id| format!("#{id}"))
+            .chain(self.classes.iter().map(|class| format!(".{class}")))
+            .chain(self.pairs.iter().map(|(key, value)| format!("{key}=\"{value}\"")))
+            .collect::<Vec<_>>();
+
+        format!("{{{}}}", entries.join(" "))
+    }
+
+    /// HTML attributes with a leading space each, empty attributes render nothing
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+
+        if let Some(id) = &self.id {
+            out.push_str(&format!(" id=\"{}\"", html::escape(id)));
+        }
+
+        if !self.classes.is_empty() {
+            out.push_str(&format!(" class=\"{}\"", html::escape(&self.classes.join(" "))));
+        }
+
+        for (key, value) in &self.pairs {
+            out.push_str(&format!(" {key}=\"{}\"", html::escape(value)));
+        }
+
+        out
+    }
+}
+
+fn is_key(key: &str) -> bool {
+    key.starts_with(|character: char| character.is_ascii_alphabetic() || character == '_')
+        && key.chars().all(|character| character.is_ascii_alphanumeric() || "-_:.".contains(character))
+}
+
+/// Whether an attribute is an event handler like `onclick`
+fn is_event_handler(key: &str) -> bool {
+    key.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("on"))
+}
+
+/// Whether the character at `index` follows an odd number of backslashes
+fn is_escaped(text: &str, index: usize) -> bool {
+    text[..index].chars().rev().take_while(|&character| character == '\\').count() % 2 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    #[test]
+    /// Attributes (extension)
+    fn test_attributes_heading() {
+        let parser = Parser::from_reader_with_options("# Title {#custom-id .note}\n\nSubtitle {.sub lang=\"en gb\"}\n---\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.attributes = true));
+        assert_eq!(
+            "<h1 id=\"custom-id\" class=\"note\">Title</h1>\n<h2 class=\"sub\" lang=\"en gb\">Subtitle</h2>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Attributes (extension): closing sequences of headings
+    fn test_attributes_heading_closing_sequence() {
+        let parser = Parser::from_reader_with_options("# Foo ## {#a}\n\n## Bar {#b} ##\n\n### Baz\\## {#c}\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.attributes = true));
+        assert_eq!(
+            "<h1 id=\"a\">Foo</h1>\n<h2 id=\"b\">Bar</h2>\n<h3 id=\"c\">Baz##</h3>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Attributes (extension): invalid or disabled attribute blocks are text
+    fn test_attributes_invalid() {
+        let parser = Parser::from_reader_with_options("# Foo {#a b}\n\n# Bar {.c}\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!(
+            "<h1>Foo {#a b}</h1>\n<h1>Bar {.c}</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+
+        let parser = Parser::from_reader_with_options("# Foo {#a b}\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.attributes = true));
+        assert_eq!("<h1>Foo {#a b}</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Attributes (extension): an escaped brace is text
+    fn test_attributes_escaped() {
+        let parser = Parser::from_reader_with_options("# Title \\{#x}\n\n# Other {#y\\}\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.attributes = true));
+        assert_eq!(
+            "<h1>Title {#x}</h1>\n<h1>Other {#y}</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Attributes (extension): event handlers are dropped
+    fn test_attributes_event_handler() {
+        let parser = Parser::from_reader_with_options("# Title {onclick=alert(1) ONLOAD=\"x\" lang=en}\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.attributes = true));
+        assert_eq!("<h1 lang=\"en\">Title</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Attributes (extension): fenced code blocks
+    fn test_attributes_fenced_code_block() {
+        let parser = Parser::from_reader_with_options("```rust {.numberLines startFrom=10}\nfn main() {}\n```\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.attributes = true));
+        let document = parser.parse().unwrap();
+
+        match document.blocks() {
+            [Block::Leaf(Leaf::FencedCodeBlock { info, attributes, .. })] => {
+                assert_eq!(Some("rust"), info.as_deref());
+                assert_eq!(vec!["numberLines".to_string()], attributes.classes);
+                assert_eq!(vec![("startFrom".to_string(), "10".to_string())], attributes.pairs);
+            }
+            blocks => panic!("unexpected blocks {blocks:?}"),
+        }
+
+        assert_eq!(
+            "<pre class=\"numberLines\" startFrom=\"10\"><code class=\"language-rust\">fn main() {}\n</code></pre>\n",
+            document.to_html(&RenderOptions::default())
+        );
+    }
+
+    #[test]
+    /// Attributes (extension): identifiers reserved ahead of generated ones
+    fn test_attributes_heading_ids() {
+        let options = ParseOptions {
+            heading_ids: true,
+            ..ParseOptions::gfm().with_constructs(|constructs| constructs.attributes = true)
+        };
+
+        let parser = Parser::from_reader_with_options("# Intro\n\n# Other {#intro}\n".as_bytes(), options);
+        assert_eq!(
+            "<h1 id=\"intro-1\">Intro</h1>\n<h1 id=\"intro\">Other</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+}
@@ -1,16 +1,18 @@
+use crate::parser::document::container::Container;
 use crate::parser::document::leaf::Leaf;
+use crate::parser::options::RenderOptions;
 
 #[derive(Debug, Clone)]
 pub enum Block {
-    Container,
+    Container(Container),
     Leaf(Leaf),
 }
 
 impl Block {
-    pub fn to_html(&self) -> String {
+    pub fn to_html(&self, options: &RenderOptions) -> String {
         match self {
-            Block::Container => String::new(),
-            Block::Leaf(leaf) => leaf.to_html(),
+            Block::Container(container) => container.to_html(options),
+            Block::Leaf(leaf) => leaf.to_html(options),
         }
     }
 }
\ No newline at end of file
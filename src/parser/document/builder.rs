@@ -0,0 +1,176 @@
+use crate::error::Error;
+use crate::parser::document::attributes::Attributes;
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind, ListKind};
+use crate::parser::document::heading::HeadingMeta;
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::Document;
+use crate::unicode;
+
+const MAX_HEADING_LEVEL: usize = 6;
+const MAX_LIST_START: usize = 999_999_999;
+
+/// # Builder
+/// Fluent construction of a [Document] or of the content of a [Container]
+///
+/// Invariants of the produced nodes are checked while building, the first violation is reported
+/// by [Builder::build] or [Builder::into_blocks].
+///
+/// ## Invariants
+/// * Heading levels range from 1 to 6
+/// * Ordered lists start at most at 999999999
+pub struct Builder {
+    content: Vec<Block>,
+    error: Option<Error>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self {
+            content: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Heading with literal text, no inline markup is interpreted
+    pub fn heading(self, level: usize, text: impl Into<String>) -> Self {
+        if !(1..=MAX_HEADING_LEVEL).contains(&level) {
+            return self.fail(Error::HeadingLevel(level));
+        }
+
+        self.block(Leaf::AtxHeading {
+            level,
+            content: vec![Inline::Text(text.into())],
+            meta: HeadingMeta::default(),
+        })
+    }
+
+    pub fn thematic_break(self) -> Self {
+        self.block(Leaf::ThematicBreak)
+    }
+
+    pub fn paragraph(self, content: impl IntoIterator<Item=Inline>) -> Self {
+        self.block(Leaf::Paragraph {
+            content: content.into_iter().collect(),
+        })
+    }
+
+    /// Fenced code block, an empty `info` string results in a code block without language
+    pub fn code_block(self, info: impl Into<String>, text: impl Into<String>) -> Self {
+        let info = info.into();
+        let mut text = text.into();
+
+        if !text.is_empty() && !text.ends_with(unicode::LINE_FEED) {
+            text.push(unicode::LINE_FEED);
+        }
+
+        self.block(Leaf::FencedCodeBlock {
+            text,
+            info: Some(info).filter(|info| !info.is_empty()),
+            attributes: Attributes::default(),
+        })
+    }
+
+    pub fn indented_code_block(self, text: impl Into<String>) -> Self {
+        self.block(Leaf::IndentedCodeBlock {
+            text: text.into()
+                .trim_end_matches(unicode::LINE_FEED)
+                .to_string(),
+        })
+    }
+
+    /// List with one item per builder
+    pub fn list(self, kind: ListKind, tight: bool, items: impl IntoIterator<Item=Builder>) -> Self {
+        if let ListKind::Ordered { start } = kind {
+            if start > MAX_LIST_START {
+                return self.fail(Error::ListStart(start));
+            }
+        }
+
+        let items = items.into_iter()
+            .map(|item| item.into_blocks()
+                .map(|content| Container::new(ContainerKind::ListItem, content).into())
+            )
+            .collect::<Result<Vec<Block>, Error>>();
+
+        match items {
+            Ok(items) => self.block(Container::new(ContainerKind::List { kind, tight }, items)),
+            Err(error) => self.fail(error),
+        }
+    }
+
+    pub fn block(mut self, block: impl Into<Block>) -> Self {
+        self.content.push(block.into());
+        self
+    }
+
+    pub fn into_blocks(self) -> Result<Vec<Block>, Error> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.content),
+        }
+    }
+
+    pub fn build(self) -> Result<Document, Error> {
+        let mut document = Document::new();
+
+        for block in self.into_blocks()? {
+            document.push(block);
+        }
+
+        Ok(document)
+    }
+
+    fn fail(mut self, error: Error) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::parser::document::container::ListKind;
+    use crate::parser::document::Document;
+
+    use super::Builder;
+
+    #[test]
+    /// Builder: document construction
+    fn test_builder() {
+        let document = Document::builder()
+            .heading(2, "Release notes")
+            .list(ListKind::Bullet, true, [
+                Builder::new().paragraph(["foo".into()]),
+                Builder::new().paragraph(["bar".into()]).code_block("rust", "let a = 1;"),
+            ])
+            .list(ListKind::Ordered { start: 3 }, false, [
+                Builder::new().paragraph(["baz".into()]),
+            ])
+            .thematic_break()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            "<h2>Release notes</h2>\n\
+            <ul>\n<li>foo</li>\n<li>bar\n<pre><code class=\"language-rust\">let a = 1;\n</code></pre>\n</li>\n</ul>\n\
+            <ol start=\"3\">\n<li>\n<p>baz</p>\n</li>\n</ol>\n\
+            <hr />\n",
+            document.to_string(),
+        );
+    }
+
+    #[test]
+    /// Builder: heading level validation
+    fn test_builder_heading_level() {
+        assert!(matches!(Document::builder().heading(7, "foo").build(), Err(Error::HeadingLevel(7))));
+        assert!(matches!(Document::builder().heading(0, "foo").build(), Err(Error::HeadingLevel(0))));
+    }
+}
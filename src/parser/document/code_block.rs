@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::parser::document::attributes::Attributes;
+use crate::parser::document::code_info::CodeInfo;
+use crate::parser::document::html;
+use crate::parser::document::registry::Registry;
+
+/// Languages of the diagram fences registered by [CodeBlockHandlers::diagrams]
+pub const DIAGRAM_LANGUAGES: [&str; 4] = ["mermaid", "plantuml", "dot", "graphviz"];
+
+/// # Fenced code
+/// Fenced code block handed to a [CodeBlockHandler]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FencedCode<'a> {
+    /// Language of the info string
+    pub language: &'a str,
+    /// Whole info string, without a trailing attribute block
+    pub info: &'a str,
+    /// Metadata parsed from the info string
+    pub metadata: &'a CodeInfo,
+    /// Content of the code block as parsed
+    pub text: &'a str,
+    /// Attributes of the trailing attribute block of the info string
+    pub attributes: &'a Attributes,
+}
+
+/// Renders a fenced code block to HTML
+pub type CodeBlockHandler = Arc<dyn Fn(&FencedCode) -> String + Send + Sync>;
+
+/// Handlers rendering fenced code blocks by language, in place of `<pre><code>`
+pub type CodeBlockHandlers = Registry<dyn Fn(&FencedCode) -> String + Send + Sync>;
+
+impl CodeBlockHandlers {
+    /// Handlers rendering the fences of the [DIAGRAM_LANGUAGES] as diagrams
+    pub fn diagrams() -> Self {
+        let mut handlers = Self::default();
+        for language in DIAGRAM_LANGUAGES {
+            handlers.register_diagram(language);
+        }
+        handlers
+    }
+
+    /// Registers the handler of the fences of `language`, replacing a previous one
+    pub fn register(&mut self, language: impl Into<String>, handler: impl Fn(&FencedCode) -> String + Send + Sync + 'static) {
+        self.insert(language, Arc::new(handler));
+    }
+
+    /// Renders the fences of `language` as `<pre class="{language}">` around the escaped text,
+    /// the element diagram libraries like Mermaid look for, without a `<code>` element
+    pub fn register_diagram(&mut self, language: impl Into<String>) {
+        self.register(language, diagram);
+    }
+}
+
+fn diagram(code: &FencedCode) -> String {
+    let mut attributes = code.attributes.clone();
+    attributes.classes.insert(0, code.language.to_string());
+
+    format!("<pre{}>{}</pre>\n", attributes.to_html(), html::escape(code.text))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    use super::{CodeBlockHandlers, FencedCode};
+
+    #[test]
+    /// Code block handlers: diagrams
+    fn test_code_block_diagrams() {
+        let parser = Parser::from_reader_with_options("```mermaid {#flow .wide}\ngraph TD\n  A-->B\n```\n\n```dot\ndigraph { a -> b }\n```\n\n```rust\nlet a = 1;\n```\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.attributes = true));
+        let document = parser.parse().unwrap();
+
+        let options = RenderOptions {
+            code_block_handlers: CodeBlockHandlers::diagrams(),
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<pre id=\"flow\" class=\"mermaid wide\">graph TD\n  A--&gt;B\n</pre>\n<pre class=\"dot\">digraph { a -&gt; b }\n</pre>\n<pre><code class=\"language-rust\">let a = 1;\n</code></pre>\n",
+            document.to_html(&options)
+        );
+        assert_eq!(
+            "<pre id=\"flow\" class=\"wide\"><code class=\"language-mermaid\">graph TD\n  A-->B\n</code></pre>\n<pre><code class=\"language-dot\">digraph { a -> b }\n</code></pre>\n<pre><code class=\"language-rust\">let a = 1;\n</code></pre>\n",
+            document.to_html(&RenderOptions::default())
+        );
+    }
+
+    #[test]
+    /// Code block handlers: registered language
+    fn test_code_block_handler() {
+        let parser = Parser::from_reader("```plantuml theme=dark\nAlice -> Bob\n```\n".as_bytes());
+        let document = parser.parse().unwrap();
+
+        let mut options = RenderOptions::default();
+        options.code_block_handlers.register("plantuml", |code: &FencedCode| {
+            format!("<svg data-info=\"{}\" data-lines=\"{}\"></svg>\n", code.info, code.text.lines().count())
+        });
+
+        assert_eq!("<svg data-info=\"plantuml theme=dark\" data-lines=\"1\"></svg>\n", document.to_html(&options));
+    }
+}
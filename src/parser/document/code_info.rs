@@ -0,0 +1,241 @@
+use std::ops::RangeInclusive;
+
+const LINE_NUMBERS: &str = "linenos";
+const TITLE: &str = "title";
+
+/// # Code info
+/// Metadata of the info string of a fenced code block
+///
+/// The first word is the language, followed by rustdoc attributes separated by commas as in
+/// `rust,ignore,should_panic`. The words after it are the metadata, of which `{1,3-5}` line
+/// ranges, `title="main.rs"` and `linenos` are understood.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CodeInfo {
+    pub language: Option<String>,
+    /// Comma separated attributes following the language
+    pub attributes: Vec<String>,
+    /// Everything after the first word as written, the whole info string if it starts with line
+    /// ranges
+    pub meta: String,
+    /// 1-based lines of `{1,3-5}` ranges
+    pub highlighted_lines: Vec<RangeInclusive<usize>>,
+    pub title: Option<String>,
+    /// Whether `linenos` asks for line numbers
+    pub line_numbers: bool,
+}
+
+impl CodeInfo {
+    /// Info string taken as written, the language is its first word and the rest the metadata
+    pub fn unparsed(info: &str) -> Self {
+        let info = info.trim();
+        let (language, meta) = info.split_once(char::is_whitespace).unwrap_or((info, ""));
+
+        Self {
+            language: Some(language.to_string()).filter(|language| !language.is_empty()),
+            meta: meta.trim_start().to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn parse(info: &str) -> Self {
+        let info = info.trim();
+        let (first, rest) = info.split_once(char::is_whitespace).unwrap_or((info, ""));
+        let mut code_info = Self::default();
+
+        let meta = match line_ranges(first) {
+            Some(_) => info,
+            None => {
+                let mut parts = first.split(',').filter(|part| !part.is_empty());
+                code_info.language = parts.next().map(str::to_string);
+                code_info.attributes = parts.map(str::to_string).collect();
+                rest.trim_start()
+            }
+        };
+        code_info.meta = meta.to_string();
+
+        for word in words(meta) {
+            if let Some(ranges) = line_ranges(&word) {
+                code_info.highlighted_lines.extend(ranges);
+            } else if word == LINE_NUMBERS {
+                code_info.line_numbers = true;
+            } else if let Some(title) = word.strip_prefix(TITLE).and_then(|rest| rest.strip_prefix('=')) {
+                code_info.title = Some(title.to_string());
+            }
+        }
+
+        code_info
+    }
+
+    pub fn is_highlighted(&self, line: usize) -> bool {
+        self.highlighted_lines.iter().any(|range| range.contains(&line))
+    }
+
+    /// Whether the lines of the code block are rendered wrapped
+    pub fn has_line_wrappers(&self) -> bool {
+        self.line_numbers || !self.highlighted_lines.is_empty()
+    }
+
+    /// Rendered lines of a code block, each including its newline, in `<span class="line">` with a
+    /// `highlighted` class and a `data-line` number as asked, or joined as they are without
+    /// wrappers
+    pub fn wrap_lines(&self, lines: impl IntoIterator<Item = impl AsRef<str>>) -> String {
+        if !self.has_line_wrappers() {
+            return lines.into_iter().map(|line| line.as_ref().to_string()).collect();
+        }
+
+        lines.into_iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let number = index + 1;
+                let highlighted = if self.is_highlighted(number) { " highlighted" } else { "" };
+                let data_line = if self.line_numbers { format!(" data-line=\"{number}\"") } else { String::new() };
+                format!("<span class=\"line{highlighted}\"{data_line}>{}</span>", line.as_ref())
+            })
+            .collect()
+    }
+}
+
+/// Words separated by whitespace outside of quotes, with the quotes removed
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quote = None;
+
+    for character in text.chars() {
+        match (quote, character) {
+            (None, '"' | '\'') => quote = Some(character),
+            (Some(open), _) if open == character => quote = None,
+            (None, character) if character.is_whitespace() => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            (_, character) => word.push(character),
+        }
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Ranges of `{1,3-5}`, [None] unless every range is valid
+fn line_ranges(word: &str) -> Option<Vec<RangeInclusive<usize>>> {
+    word.strip_prefix('{')?
+        .strip_suffix('}')?
+        .split(',')
+        .map(|range| {
+            let (start, end) = range.trim().split_once('-').unwrap_or((range.trim(), range.trim()));
+            let (start, end) = (start.trim().parse().ok()?, end.trim().parse().ok()?);
+            (start > 0 && start <= end).then_some(start..=end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::code_block::FencedCode;
+    use crate::parser::document::highlight::Highlighter;
+    use crate::parser::options::RenderOptions;
+    use crate::parser::Parser;
+
+    use super::CodeInfo;
+
+    #[test]
+    /// Code info: parsing
+    fn test_code_info() {
+        assert_eq!(
+            CodeInfo {
+                language: Some("rust".to_string()),
+                attributes: vec!["ignore".to_string(), "should_panic".to_string()],
+                meta: "{1,3-5} title=\"main file.rs\" linenos".to_string(),
+                highlighted_lines: vec![1..=1, 3..=5],
+                title: Some("main file.rs".to_string()),
+                line_numbers: true,
+            },
+            CodeInfo::parse("rust,ignore,should_panic {1,3-5} title=\"main file.rs\" linenos")
+        );
+        assert_eq!(
+            CodeInfo {
+                meta: "{2} linenos".to_string(),
+                highlighted_lines: vec![2..=2],
+                line_numbers: true,
+                ..CodeInfo::default()
+            },
+            CodeInfo::parse("{2} linenos")
+        );
+        assert_eq!(
+            CodeInfo {
+                language: Some("ruby".to_string()),
+                meta: "startline=3 {0,2} {5-4} $%@#$".to_string(),
+                ..CodeInfo::default()
+            },
+            CodeInfo::parse("ruby startline=3 {0,2} {5-4} $%@#$")
+        );
+    }
+
+    #[test]
+    /// Code info: rendering
+    fn test_code_info_rendering() {
+        let parser = Parser::from_reader("```rust,ignore {2} linenos\nfn main() {\n    todo!()\n}\n```\n\n```{1}\nplain\n```\n\n```rust title=main.rs\nfn main() {}\n```\n".as_bytes());
+        let document = parser.parse().unwrap();
+
+        match document.blocks() {
+            [Block::Leaf(leaf), ..] => assert_eq!(Some(vec![2..=2]), leaf.code_info().map(|info| info.highlighted_lines)),
+            blocks => panic!("unexpected blocks {blocks:?}"),
+        }
+
+        assert_eq!(
+            "<pre><code class=\"language-rust,ignore\">fn main() {\n    todo!()\n}\n</code></pre>\n\
+             <pre><code class=\"language-{1}\">plain\n</code></pre>\n\
+             <pre><code class=\"language-rust\">fn main() {}\n</code></pre>\n",
+            document.to_html(&RenderOptions::default())
+        );
+
+        let mut options = RenderOptions {
+            parse_code_info: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<pre><code class=\"language-rust\"><span class=\"line\" data-line=\"1\">fn main() {\n</span><span class=\"line highlighted\" data-line=\"2\">    todo!()\n</span><span class=\"line\" data-line=\"3\">}\n</span></code></pre>\n\
+             <pre><code><span class=\"line highlighted\">plain\n</span></code></pre>\n\
+             <pre><code class=\"language-rust\">fn main() {}\n</code></pre>\n",
+            document.to_html(&options)
+        );
+
+        options.code_block_handlers.register("rust", |code: &FencedCode| {
+            format!("<figure><figcaption>{}</figcaption></figure>\n", code.metadata.title.as_deref().unwrap_or(code.info))
+        });
+
+        assert!(document.to_html(&options).ends_with("<figure><figcaption>main.rs</figcaption></figure>\n"));
+    }
+
+    #[test]
+    /// Code info: highlighted lines around highlighter output
+    fn test_code_info_highlighter() {
+        let parser = Parser::from_reader("```rust {1} linenos\nlet a = 1;\nlet b = 2;\n```\n".as_bytes());
+        let document = parser.parse().unwrap();
+
+        let highlighter = Highlighter::new(|_: &str, text: &str, info: &CodeInfo| {
+            Some(info.wrap_lines(text.split_inclusive('\n').map(|line| format!("<b>{line}</b>"))))
+        });
+        let options = RenderOptions {
+            highlighter: Some(highlighter.clone()),
+            parse_code_info: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<pre><code class=\"language-rust\"><span class=\"line highlighted\" data-line=\"1\"><b>let a = 1;\n</b></span><span class=\"line\" data-line=\"2\"><b>let b = 2;\n</b></span></code></pre>\n",
+            document.to_html(&options)
+        );
+        assert_eq!(
+            "<pre><code class=\"language-rust\"><b>let a = 1;\n</b><b>let b = 2;\n</b></code></pre>\n",
+            document.to_html(&RenderOptions { highlighter: Some(highlighter), ..RenderOptions::default() })
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::parser::document::block::Block;
+use crate::parser::document::directive::Directive;
+use crate::parser::document::html;
+use crate::parser::document::inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::options::RenderOptions;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListKind {
+    Bullet,
+    Ordered {
+        start: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerKind {
+    List {
+        kind: ListKind,
+        tight: bool,
+    },
+    ListItem,
+    /// GFM task list item, rendered with a disabled checkbox
+    TaskListItem {
+        checked: bool,
+    },
+    /// Section listing the referenced footnote definitions in order of their first reference
+    Footnotes,
+    /// GFM footnote definition, `index` is its number and `references` the number of references
+    /// to it
+    FootnoteDefinition {
+        label: String,
+        index: usize,
+        references: usize,
+    },
+    /// Terms and their definitions, `tight` lists render the definitions without paragraphs
+    DefinitionList {
+        tight: bool,
+    },
+    DefinitionTerm,
+    DefinitionDescription,
+    /// Callout of a `kind` such as `note` or `warning`, titled by the capitalized kind unless
+    /// `title` is given
+    Admonition {
+        kind: String,
+        title: Option<String>,
+    },
+    /// `:::name[label]{attributes}` container directive
+    Directive(Directive),
+}
+
+#[derive(Debug, Clone)]
+pub struct Container {
+    pub kind: ContainerKind,
+    pub content: Vec<Block>,
+}
+
+impl Container {
+    pub fn new(kind: ContainerKind, content: Vec<Block>) -> Self {
+        Self {
+            kind,
+            content,
+        }
+    }
+
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        self.render(options, false)
+    }
+
+    fn render(&self, options: &RenderOptions, tight: bool) -> String {
+        match &self.kind {
+            ContainerKind::List { kind, tight } => {
+                let (open, close) = match kind {
+                    ListKind::Bullet => ("<ul>".to_string(), "</ul>"),
+                    ListKind::Ordered { start: 1 } => ("<ol>".to_string(), "</ol>"),
+                    ListKind::Ordered { start } => (format!("<ol start=\"{start}\">"), "</ol>"),
+                };
+
+                let items = self.content.iter()
+                    .map(|block| match block {
+                        Block::Container(container) => container.render(options, *tight),
+                        block => block.to_html(options),
+                    })
+                    .collect::<String>();
+
+                format!("{open}\n{items}{close}\n")
+            }
+            ContainerKind::ListItem => self.render_item(options, "li", tight, ""),
+            ContainerKind::TaskListItem { checked: false } => {
+                self.render_item(options, "li", tight, "<input disabled=\"\" type=\"checkbox\"> ")
+            }
+            ContainerKind::TaskListItem { checked: true } => {
+                self.render_item(options, "li", tight, "<input checked=\"\" disabled=\"\" type=\"checkbox\"> ")
+            }
+            ContainerKind::DefinitionList { tight } => {
+                let items = self.content.iter()
+                    .map(|block| match block {
+                        Block::Container(container) => container.render(options, *tight),
+                        block => block.to_html(options),
+                    })
+                    .collect::<String>();
+
+                format!("<dl>\n{items}</dl>\n")
+            }
+            ContainerKind::DefinitionTerm => self.render_item(options, "dt", true, ""),
+            ContainerKind::DefinitionDescription => self.render_item(options, "dd", tight, ""),
+            ContainerKind::Admonition { kind, title } => {
+                let title = title.clone().unwrap_or_else(|| capitalize(kind));
+                let content = self.content.iter()
+                    .map(|block| block.to_html(options))
+                    .collect::<String>();
+
+                format!(
+                    "<div class=\"markdown-alert markdown-alert-{}\">\n<p class=\"markdown-alert-title\">{}</p>\n{content}</div>\n",
+                    html::escape(kind),
+                    html::escape(&title),
+                )
+            }
+            ContainerKind::Directive(directive) => {
+                let content = self.content.iter()
+                    .map(|block| block.to_html(options))
+                    .collect::<String>();
+
+                directive.to_html(&content, options)
+            }
+            ContainerKind::Footnotes => {
+                let items = self.content.iter()
+                    .map(|block| block.to_html(options))
+                    .collect::<String>();
+
+                format!("<section class=\"footnotes\" data-footnotes>\n<ol>\n{items}</ol>\n</section>\n")
+            }
+            ContainerKind::FootnoteDefinition { label, index, references } => {
+                let backreferences = footnote_backreferences(label, *index, *references);
+                let mut out = format!("<li id=\"fn-{}\">\n", html::escape_href(label));
+
+                for (position, block) in self.content.iter().enumerate() {
+                    match block {
+                        Block::Leaf(Leaf::Paragraph { content }) if position + 1 == self.content.len() => {
+                            out.push_str(&format!("<p>{} {backreferences}</p>\n", inline::render(content, options)));
+                        }
+                        block => out.push_str(&block.to_html(options)),
+                    }
+                }
+
+                if !matches!(self.content.last(), Some(Block::Leaf(Leaf::Paragraph { .. }))) {
+                    out.push_str(&format!("{backreferences}\n"));
+                }
+
+                out.push_str("</li>\n");
+                out
+            }
+        }
+    }
+
+    /// Renders a list item as a `tag` element, `marker` is placed at the start of its first
+    /// paragraph
+    fn render_item(&self, options: &RenderOptions, tag: &str, tight: bool, marker: &str) -> String {
+        let mut out = format!("<{tag}>");
+        let mut marker = Some(marker).filter(|marker| !marker.is_empty());
+
+        for block in &self.content {
+            match block {
+                Block::Leaf(Leaf::Paragraph { content }) if tight => {
+                    out.push_str(marker.take().unwrap_or_default());
+                    out.push_str(&inline::render(content, options));
+                }
+                Block::Leaf(Leaf::Paragraph { content }) if marker.is_some() => {
+                    out.push_str(&format!("\n<p>{}{}</p>\n", marker.take().unwrap_or_default(), inline::render(content, options)));
+                }
+                block => {
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str(&block.to_html(options));
+                }
+            }
+        }
+
+        out.push_str(&format!("</{tag}>\n"));
+        out
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut characters = text.chars();
+
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => String::new(),
+    }
+}
+
+/// Links from a footnote definition back to each of its references
+fn footnote_backreferences(label: &str, index: usize, references: usize) -> String {
+    let label = html::escape_href(label);
+
+    (1..=references.max(1))
+        .map(|reference| match reference {
+            1 => format!(
+                "<a href=\"#fnref-{label}\" class=\"footnote-backref\" data-footnote-backref \
+                data-footnote-backref-idx=\"{index}\" aria-label=\"Back to reference {index}\">↩</a>"
+            ),
+            reference => format!(
+                " <a href=\"#fnref-{label}-{reference}\" class=\"footnote-backref\" data-footnote-backref \
+                data-footnote-backref-idx=\"{index}-{reference}\" aria-label=\"Back to reference {index}-{reference}\">\
+                ↩<sup class=\"footnote-ref\">{reference}</sup></a>"
+            ),
+        })
+        .collect()
+}
+
+impl From<Container> for Block {
+    fn from(value: Container) -> Self {
+        Block::Container(value)
+    }
+}
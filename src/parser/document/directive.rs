@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use crate::parser::document::attributes::Attributes;
+use crate::parser::document::html;
+use crate::parser::document::registry::Registry;
+use crate::parser::options::RenderOptions;
+
+/// # Directive kind
+/// Form of a generic directive, determined by the number of its leading colons
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DirectiveKind {
+    /// `:name[label]{attributes}` within inline content
+    Text,
+    /// `::name[label]{attributes}` on a line of its own
+    Leaf,
+    /// `:::name[label]{attributes}` fence enclosing blocks up to a closing `:::` fence
+    Container,
+}
+
+/// # Directive
+/// Generic directive, rendered by the handler registered for its name
+///
+/// `label` is the raw text between the brackets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive {
+    pub kind: DirectiveKind,
+    pub name: String,
+    pub label: Option<String>,
+    pub attributes: Attributes,
+}
+
+impl Directive {
+    /// Renders the directive, `content` is the rendered content of a container directive or the
+    /// escaped label of any other directive
+    pub fn to_html(&self, content: &str, options: &RenderOptions) -> String {
+        if let Some(handler) = options.directive_handlers.get(&self.name) {
+            return handler(self, content);
+        }
+
+        let text = match (self.kind, &self.label) {
+            (DirectiveKind::Container, _) | (_, Some(_)) => content.to_string(),
+            (_, None) => html::escape(&self.source()),
+        };
+
+        match (options.unhandled_directives, self.kind) {
+            (UnhandledDirectives::Drop, _) => String::new(),
+            (UnhandledDirectives::Text, DirectiveKind::Leaf) if !text.is_empty() => format!("<p>{text}</p>\n"),
+            (UnhandledDirectives::Text, DirectiveKind::Leaf) => String::new(),
+            (UnhandledDirectives::Text, _) => text,
+        }
+    }
+
+    /// Source of the directive up to its label, its attributes written as by
+    /// [Attributes::to_source]
+    pub fn source(&self) -> String {
+        let colons = match self.kind {
+            DirectiveKind::Text => ":",
+            DirectiveKind::Leaf => "::",
+            DirectiveKind::Container => ":::",
+        };
+        let label = self.label.as_ref()
+            .map(|label| format!("[{label}]"))
+            .unwrap_or_default();
+
+        format!("{colons}{}{label}{}", self.name, self.attributes.to_source())
+    }
+}
+
+/// # Unhandled directives
+/// Rendering of directives without a registered handler
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum UnhandledDirectives {
+    /// The label of a text or leaf directive is rendered as text, its source without a label,
+    /// the content of a container directive without wrapper
+    #[default]
+    Text,
+    /// Nothing is rendered
+    Drop,
+}
+
+/// Renders a directive given its rendered content
+pub type DirectiveHandler = Arc<dyn Fn(&Directive, &str) -> String + Send + Sync>;
+
+/// Handlers rendering the directives by name
+pub type DirectiveHandlers = Registry<dyn Fn(&Directive, &str) -> String + Send + Sync>;
+
+impl DirectiveHandlers {
+    /// Registers the handler of the directives named `name`, replacing a previous one
+    pub fn register(&mut self, name: impl Into<String>, handler: impl Fn(&Directive, &str) -> String + Send + Sync + 'static) {
+        self.insert(name, Arc::new(handler));
+    }
+}
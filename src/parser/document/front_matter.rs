@@ -0,0 +1,72 @@
+/// # Front matter kind
+/// Metadata format announced by the delimiter lines of a front matter block
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrontMatterKind {
+    /// Delimited by `---` lines, may be closed by a `...` line instead
+    Yaml,
+    /// Delimited by `+++` lines
+    Toml,
+}
+
+impl FrontMatterKind {
+    pub fn delimiter(&self) -> &'static str {
+        match self {
+            FrontMatterKind::Yaml => "---",
+            FrontMatterKind::Toml => "+++",
+        }
+    }
+}
+
+/// # Front matter
+/// Metadata block at the very start of a document, kept out of the rendered output
+///
+/// `raw` holds the lines between the delimiter lines, without the final line ending.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontMatter {
+    pub kind: FrontMatterKind,
+    pub raw: String,
+}
+
+impl FrontMatter {
+    pub fn new(kind: FrontMatterKind, raw: impl Into<String>) -> Self {
+        Self {
+            kind,
+            raw: raw.into(),
+        }
+    }
+
+    /// Deserializes the raw text with the format of its kind
+    #[cfg(feature = "serde")]
+    pub fn deserialize<T: serde::de::DeserializeOwned>(&self) -> Result<T, FrontMatterError> {
+        match self.kind {
+            FrontMatterKind::Yaml => serde_yaml::from_str(&self.raw).map_err(FrontMatterError::Yaml),
+            FrontMatterKind::Toml => toml::from_str(&self.raw).map_err(FrontMatterError::Toml),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum FrontMatterError {
+    Yaml(serde_yaml::Error),
+    Toml(toml::de::Error),
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{FrontMatter, FrontMatterKind};
+
+    #[test]
+    /// Front matter (extension): deserialization
+    fn test_front_matter_deserialize() {
+        let yaml = FrontMatter::new(FrontMatterKind::Yaml, "title: Hello");
+        let toml = FrontMatter::new(FrontMatterKind::Toml, "title = \"Hello\"");
+
+        let expected = BTreeMap::from([("title".to_string(), "Hello".to_string())]);
+        assert_eq!(expected, yaml.deserialize::<BTreeMap<String, String>>().unwrap());
+        assert_eq!(expected, toml.deserialize::<BTreeMap<String, String>>().unwrap());
+        assert!(FrontMatter::new(FrontMatterKind::Toml, "title: Hello").deserialize::<BTreeMap<String, String>>().is_err());
+    }
+}
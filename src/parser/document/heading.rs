@@ -0,0 +1,92 @@
+use crate::parser::document::attributes::Attributes;
+use crate::parser::document::position::Position;
+
+/// # Heading metadata
+/// Identifier, attributes and source lines of a heading
+///
+/// `id` is the identifier of the heading, when assigned, and `position` its source lines, when
+/// parsed. The identifier of a trailing attribute block becomes `id`, its classes and pairs are
+/// kept in `attributes`. More metadata may follow, other crates start from [HeadingMeta::default].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HeadingMeta {
+    pub id: Option<String>,
+    pub attributes: Attributes,
+    pub position: Option<Position>,
+}
+
+impl HeadingMeta {
+    /// Metadata of a heading with a trailing attribute block, its identifier becomes the heading id
+    pub fn from_attributes(mut attributes: Attributes) -> Self {
+        Self {
+            id: attributes.id.take(),
+            attributes,
+            position: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    use super::HeadingMeta;
+
+    #[test]
+    /// Heading identifiers (extension)
+    fn test_heading_ids() {
+        let parser = Parser::from_reader_with_options("# Hello, *World*!\nFoo_bar  Baz\n---\n## 1.2 Über `code`\n".as_bytes(), ParseOptions { heading_ids: true, ..ParseOptions::gfm() });
+        assert_eq!(
+            "<h1 id=\"hello-world\">Hello, <em>World</em>!</h1>\n<h2 id=\"foo_bar--baz\">Foo_bar  Baz</h2>\n<h2 id=\"12-über-code\">1.2 Über <code>code</code></h2>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Heading identifiers (extension): duplicates get a numeric suffix
+    fn test_heading_ids_duplicates() {
+        let parser = Parser::from_reader_with_options("# Foo\n# Foo\n# Foo 1\n# Foo-1\n# Foo\n".as_bytes(), ParseOptions { heading_ids: true, ..ParseOptions::gfm() });
+        assert_eq!(
+            "<h1 id=\"foo\">Foo</h1>\n<h1 id=\"foo-1\">Foo</h1>\n<h1 id=\"foo-1-1\">Foo 1</h1>\n<h1 id=\"foo-1-2\">Foo-1</h1>\n<h1 id=\"foo-2\">Foo</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Heading identifiers (extension): kept on the heading
+    fn test_heading_ids_on_node() {
+        let document = Parser::from_reader_with_options("## Some Title\n".as_bytes(), ParseOptions { heading_ids: true, ..ParseOptions::gfm() })
+            .parse()
+            .unwrap();
+
+        assert!(matches!(
+            document.blocks(),
+            [Block::Leaf(Leaf::AtxHeading { level: 2, meta: HeadingMeta { id: Some(id), .. }, .. })] if id == "some-title"
+        ));
+    }
+
+    #[test]
+    /// Heading identifiers (extension): anchor links
+    fn test_heading_anchor_links() {
+        let parser = Parser::from_reader_with_options("## Über\n".as_bytes(), ParseOptions { heading_ids: true, ..ParseOptions::gfm() });
+        let options = RenderOptions {
+            heading_anchor_links: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<h2 id=\"über\"><a class=\"anchor\" aria-hidden=\"true\" href=\"#%C3%BCber\"></a>Über</h2>\n",
+            parser.parse_to_string_with(&options).unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Heading identifiers (extension): disabled by default
+    fn test_heading_ids_disabled() {
+        let parser = Parser::from_reader_with_options("# Foo\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>Foo</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
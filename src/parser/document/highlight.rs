@@ -0,0 +1,245 @@
+use std::sync::Arc;
+
+use crate::parser::document::code_info::CodeInfo;
+use crate::parser::document::shared::Shared;
+
+#[cfg(feature = "syntect")]
+pub use syntect;
+#[cfg(feature = "syntect")]
+use syntect::easy::HighlightLines;
+#[cfg(feature = "syntect")]
+use syntect::highlighting::Theme;
+#[cfg(feature = "syntect")]
+use syntect::html::{self, ClassStyle, IncludeBackground};
+#[cfg(feature = "syntect")]
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxDefinition, SyntaxSet};
+#[cfg(feature = "syntect")]
+use syntect::util::LinesWithEndings;
+
+/// # Highlight
+/// Syntax highlighting of the content of fenced code blocks
+///
+/// Implemented by functions taking the language, the text and the info of a code block.
+pub trait Highlight: Send + Sync {
+    /// HTML of the highlighted `text` placed inside the `<code>` element, [None] for a `language`
+    /// the highlighter does not know, which is rendered as a plain code block
+    ///
+    /// The line wrappers asked by `info` are left to the highlighter, e.g. by
+    /// [CodeInfo::wrap_lines] of the highlighted lines.
+    fn highlight(&self, language: &str, text: &str, info: &CodeInfo) -> Option<String>;
+}
+
+impl<F: Fn(&str, &str, &CodeInfo) -> Option<String> + Send + Sync> Highlight for F {
+    fn highlight(&self, language: &str, text: &str, info: &CodeInfo) -> Option<String> {
+        self(language, text, info)
+    }
+}
+
+/// # Highlighter
+/// Highlights fenced code blocks by the first word of their info string
+#[derive(Debug, Clone, PartialEq)]
+pub struct Highlighter {
+    highlight: Shared<dyn Highlight>,
+}
+
+impl Highlighter {
+    pub fn new(highlight: impl Highlight + 'static) -> Self {
+        Self {
+            highlight: Shared::new(Arc::new(highlight)),
+        }
+    }
+
+    pub fn highlight(&self, language: &str, text: &str, info: &CodeInfo) -> Option<String> {
+        self.highlight.highlight(language, text, info)
+    }
+}
+
+/// # Syntect style
+/// Styling of the tokens highlighted by [SyntectHighlighter]
+#[cfg(feature = "syntect")]
+#[derive(Debug)]
+pub enum SyntectStyle {
+    /// `<span class>` with the scopes of the tokens, styled by a stylesheet like the one of
+    /// [css_for_theme_with_class_style](syntect::html::css_for_theme_with_class_style)
+    Classes(ClassStyle),
+    /// `<span style>` with the colors and font styles of the theme
+    Inline(Box<Theme>),
+}
+
+/// # Syntect highlighter
+/// [Highlight] using the Sublime Text syntax definitions of `syntect`
+///
+/// Languages are found by the name or file extension of a syntax, ignoring case.
+#[cfg(feature = "syntect")]
+#[derive(Debug)]
+pub struct SyntectHighlighter {
+    syntaxes: SyntaxSet,
+    style: SyntectStyle,
+    fallback: Option<String>,
+}
+
+#[cfg(feature = "syntect")]
+impl SyntectHighlighter {
+    /// Highlighter of the default syntaxes of `syntect`
+    pub fn new(style: SyntectStyle) -> Self {
+        Self {
+            syntaxes: SyntaxSet::load_defaults_newlines(),
+            style,
+            fallback: None,
+        }
+    }
+
+    /// Replaces the syntaxes, which must be loaded for lines including their newline
+    pub fn with_syntaxes(mut self, syntaxes: SyntaxSet) -> Self {
+        self.syntaxes = syntaxes;
+        self
+    }
+
+    /// Adds a custom syntax definition, e.g. loaded with [SyntaxDefinition::load_from_str]
+    pub fn with_syntax(mut self, definition: SyntaxDefinition) -> Self {
+        let mut builder = std::mem::take(&mut self.syntaxes).into_builder();
+        builder.add(definition);
+        self.syntaxes = builder.build();
+        self
+    }
+
+    /// Highlights the languages without a syntax like `language`, rather than as plain code blocks
+    pub fn with_fallback(mut self, language: impl Into<String>) -> Self {
+        self.fallback = Some(language.into());
+        self
+    }
+}
+
+#[cfg(feature = "syntect")]
+impl Highlight for SyntectHighlighter {
+    fn highlight(&self, language: &str, text: &str, info: &CodeInfo) -> Option<String> {
+        let syntax = self.syntaxes.find_syntax_by_token(language)
+            .or_else(|| self.syntaxes.find_syntax_by_token(self.fallback.as_deref()?))?;
+
+        let mut lines = Vec::new();
+        match &self.style {
+            SyntectStyle::Classes(style) => {
+                let mut state = ParseState::new(syntax);
+                let mut stack = ScopeStack::new();
+                for line in LinesWithEndings::from(text) {
+                    // Every line reopens the spans of the scopes still open and closes them again,
+                    // so that lines can be wrapped
+                    let mut out = stack.as_slice().iter()
+                        .map(|scope| format!("<span class=\"{}\">", classes(*scope, *style)))
+                        .collect::<String>();
+                    let operations = state.parse_line(line, &self.syntaxes).ok()?;
+                    out.push_str(&html::line_tokens_to_classed_spans(line, &operations, *style, &mut stack).ok()?.0);
+                    out.push_str(&"</span>".repeat(stack.len()));
+                    lines.push(out);
+                }
+            }
+            SyntectStyle::Inline(theme) => {
+                let mut highlighter = HighlightLines::new(syntax, theme);
+                for line in LinesWithEndings::from(text) {
+                    let regions = highlighter.highlight_line(line, &self.syntaxes).ok()?;
+                    lines.push(html::styled_line_to_highlighted_html(&regions, IncludeBackground::No).ok()?);
+                }
+            }
+        }
+
+        Some(info.wrap_lines(lines))
+    }
+}
+
+/// Classes of the atoms of a scope, as the spans of `syntect` have them
+#[cfg(feature = "syntect")]
+fn classes(scope: Scope, style: ClassStyle) -> String {
+    let prefix = match style {
+        ClassStyle::SpacedPrefixed { prefix } => prefix,
+        _ => "",
+    };
+
+    scope.build_string()
+        .split('.')
+        .map(|atom| format!("{prefix}{atom}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::code_info::CodeInfo;
+    use crate::parser::options::RenderOptions;
+    use crate::parser::Parser;
+
+    use super::Highlighter;
+
+    #[test]
+    /// Highlighter
+    fn test_highlighter() {
+        let parser = Parser::from_reader("```rust\nlet a = 1;\n```\n\n```unknown\nplain\n```\n\n```\nnone\n```\n".as_bytes());
+        let document = parser.parse().unwrap();
+
+        let options = RenderOptions {
+            highlighter: Some(Highlighter::new(|language: &str, text: &str, _: &CodeInfo| {
+                (language == "rust").then(|| format!("<span class=\"keyword\">{}</span>", text.trim_end()))
+            })),
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<pre><code class=\"language-rust\"><span class=\"keyword\">let a = 1;</span></code></pre>\n<pre><code class=\"language-unknown\">plain\n</code></pre>\n<pre><code>none\n</code></pre>\n",
+            document.to_html(&options)
+        );
+    }
+
+    #[cfg(feature = "syntect")]
+    #[test]
+    /// Highlighter: syntect
+    fn test_syntect_highlighter() {
+        use syntect::highlighting::ThemeSet;
+        use syntect::html::ClassStyle;
+        use syntect::parsing::SyntaxDefinition;
+
+        use super::{SyntectHighlighter, SyntectStyle};
+
+        let parser = Parser::from_reader("```rust\nlet a = 1;\n```\n\n```unknown\n<plain>\n```\n\n```greeting\nhello\n```\n".as_bytes());
+        let document = parser.parse().unwrap();
+
+        let greeting = SyntaxDefinition::load_from_str("name: Greeting\nfile_extensions: [greeting]\nscope: source.greeting\ncontexts:\n  main:\n    - match: hello\n      scope: keyword.greeting\n", true, None).unwrap();
+        let classes = SyntectHighlighter::new(SyntectStyle::Classes(ClassStyle::Spaced))
+            .with_syntax(greeting)
+            .with_fallback("txt");
+        let options = RenderOptions {
+            highlighter: Some(Highlighter::new(classes)),
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<pre><code class=\"language-rust\"><span class=\"source rust\"><span class=\"storage type rust\">let</span> a <span class=\"keyword operator rust\">=</span> <span class=\"constant numeric integer decimal rust\">1</span><span class=\"punctuation terminator rust\">;</span>\n</span></code></pre>\n\
+             <pre><code class=\"language-unknown\"><span class=\"text plain\">&lt;plain&gt;\n</span></code></pre>\n\
+             <pre><code class=\"language-greeting\"><span class=\"source greeting\"><span class=\"keyword greeting\">hello</span>\n</span></code></pre>\n",
+            document.to_html(&options)
+        );
+
+        let theme = ThemeSet::load_defaults().themes.remove("InspiredGitHub").unwrap();
+        let options = RenderOptions {
+            highlighter: Some(Highlighter::new(SyntectHighlighter::new(SyntectStyle::Inline(Box::new(theme))))),
+            ..RenderOptions::default()
+        };
+        let html = document.to_html(&options);
+
+        assert!(html.starts_with("<pre><code class=\"language-rust\"><span style=\"font-weight:bold;color:#a71d5d;\">let</span>"));
+        assert!(html.ends_with("<pre><code class=\"language-greeting\">hello\n</code></pre>\n"));
+
+        let parser = Parser::from_reader("```rust {2} linenos\n/* a\nb */\n```\n".as_bytes());
+        let options = RenderOptions {
+            highlighter: Some(Highlighter::new(SyntectHighlighter::new(SyntectStyle::Classes(ClassStyle::Spaced)))),
+            parse_code_info: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<pre><code class=\"language-rust\">\
+             <span class=\"line\" data-line=\"1\"><span class=\"source rust\"><span class=\"comment block rust\"><span class=\"punctuation definition comment rust\">/*</span> a\n</span></span></span>\
+             <span class=\"line highlighted\" data-line=\"2\"><span class=\"source rust\"><span class=\"comment block rust\">b <span class=\"punctuation definition comment rust\">*/</span></span>\n</span></span>\
+             </code></pre>\n",
+            parser.parse().unwrap().to_html(&options)
+        );
+    }
+}
@@ -0,0 +1,105 @@
+/// Escapes the characters with a special meaning in HTML text and attribute values
+pub fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            character => out.push(character),
+        }
+    }
+
+    out
+}
+
+/// Escapes a link destination for use in a `href` attribute
+///
+/// Characters with a meaning in URLs are kept, every other character is percent encoded.
+pub fn escape_href(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '&' => out.push_str("&amp;"),
+            '\'' => out.push_str("&#x27;"),
+            character if character.is_ascii_alphanumeric() || "-_.+!*(),%#@?=;:/$~".contains(character) => {
+                out.push(character)
+            }
+            character => {
+                let mut buffer = [0; 4];
+                for byte in character.encode_utf8(&mut buffer).bytes() {
+                    out.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Tags disallowed by GFM in raw HTML
+const DISALLOWED_TAGS: [&str; 9] = [
+    "title", "textarea", "style", "xmp", "iframe", "noembed", "noframes", "script", "plaintext",
+];
+
+/// Escapes the leading `<` of every opening or closing tag disallowed by GFM
+pub fn filter_tags(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(index) = rest.find('<') {
+        out.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        match is_disallowed_tag(rest) {
+            true => out.push_str("&lt;"),
+            false => out.push('<'),
+        }
+        rest = &rest[1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn is_disallowed_tag(tag: &str) -> bool {
+    let name = tag[1..].strip_prefix('/').unwrap_or(&tag[1..]);
+
+    DISALLOWED_TAGS.iter().any(|disallowed| match name.get(..disallowed.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(disallowed) => {
+            let rest = &name[disallowed.len()..];
+            rest.starts_with(|next: char| next.is_ascii_whitespace() || next == '>') || rest.starts_with("/>")
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    #[test]
+    /// Disallowed Raw HTML (extension)
+    fn test_tagfilter_html_block() {
+        let parser = Parser::from_reader_with_options("<blockquote>\n  <xmp> is disallowed.  <XMP> is also disallowed.\n</blockquote>\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<blockquote>\n  &lt;xmp> is disallowed.  &lt;XMP> is also disallowed.\n</blockquote>\n", parser.parse_to_string_with(&RenderOptions::gfm()).unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Disallowed Raw HTML (extension)
+    fn test_tagfilter_inline_html() {
+        let parser = Parser::from_reader_with_options("# <strong> <title> <style> <em> </script> <textarea/>\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1><strong> &lt;title> &lt;style> <em> &lt;/script> &lt;textarea/></h1>\n", parser.parse_to_string_with(&RenderOptions::gfm()).unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Disallowed Raw HTML (extension): disabled by default
+    fn test_tagfilter_disabled() {
+        let parser = Parser::from_reader_with_options("<div>\n<title>foo</title>\n</div>\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<div>\n<title>foo</title>\n</div>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::parser::document::directive::Directive;
+use crate::parser::document::html;
+use crate::parser::document::wiki_link::WikiLink;
+use crate::parser::options::{EmojiRendering, RenderOptions};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    /// Inline source not yet processed by the inline parser, rendered as text
+    Raw(String),
+    Text(String),
+    Code(String),
+    /// Raw TeX source of `$inline$` or `$$display$$` math
+    Math {
+        display: bool,
+        text: String,
+    },
+    /// Inline raw HTML, rendered verbatim
+    Html(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    Strikethrough(Vec<Inline>),
+    Superscript(Vec<Inline>),
+    Subscript(Vec<Inline>),
+    /// Highlighted text
+    Mark(Vec<Inline>),
+    /// Inserted text
+    Inserted(Vec<Inline>),
+    Link {
+        destination: String,
+        title: Option<String>,
+        content: Vec<Inline>,
+    },
+    /// `reference` counts the references to the same footnote, starting at 1
+    FootnoteReference {
+        label: String,
+        index: usize,
+        reference: usize,
+    },
+    /// `:name[label]{attributes}` text directive
+    Directive(Directive),
+    /// `[[Target|Label]]` link to a wiki page
+    WikiLink(WikiLink),
+    /// Occurrence of an abbreviation, `title` is its expansion
+    Abbreviation {
+        text: String,
+        title: String,
+    },
+    /// `:name:` shortcode of an emoji, `emoji` is its Unicode character sequence
+    Emoji {
+        name: String,
+        emoji: String,
+    },
+}
+
+impl Inline {
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        match self {
+            Inline::Raw(text) |
+            Inline::Text(text) => html::escape(text),
+            Inline::Code(code) => format!("<code>{}</code>", html::escape(code)),
+            Inline::Math { display: false, text } => format!("<span class=\"math inline\">{}</span>", html::escape(text)),
+            Inline::Math { display: true, text } => format!("<span class=\"math display\">{}</span>", html::escape(text)),
+            Inline::Html(raw) if options.gfm_tagfilter => html::filter_tags(raw),
+            Inline::Html(raw) => raw.clone(),
+            Inline::Emphasis(content) => format!("<em>{}</em>", render(content, options)),
+            Inline::Strong(content) => format!("<strong>{}</strong>", render(content, options)),
+            Inline::Strikethrough(content) => format!("<del>{}</del>", render(content, options)),
+            Inline::Superscript(content) => format!("<sup>{}</sup>", render(content, options)),
+            Inline::Subscript(content) => format!("<sub>{}</sub>", render(content, options)),
+            Inline::Mark(content) => format!("<mark>{}</mark>", render(content, options)),
+            Inline::Inserted(content) => format!("<ins>{}</ins>", render(content, options)),
+            Inline::Link { destination, title, content } => {
+                let title = title.as_ref()
+                    .map(|title| format!(" title=\"{}\"", html::escape(title)))
+                    .unwrap_or_default();
+
+                format!("<a href=\"{}\"{title}>{}</a>", html::escape_href(destination), render(content, options))
+            }
+            Inline::FootnoteReference { label, index, reference } => {
+                let label = html::escape_href(label);
+                let suffix = match reference {
+                    1 => String::new(),
+                    reference => format!("-{reference}"),
+                };
+
+                format!("<sup class=\"footnote-ref\"><a href=\"#fn-{label}\" id=\"fnref-{label}{suffix}\" data-footnote-ref>{index}</a></sup>")
+            }
+            Inline::Directive(directive) => directive.to_html(&html::escape(directive.label.as_deref().unwrap_or_default()), options),
+            Inline::WikiLink(link) => link.to_html(options),
+            Inline::Abbreviation { text, title } => format!("<abbr title=\"{}\">{}</abbr>", html::escape(title), html::escape(text)),
+            Inline::Emoji { name, emoji } => match &options.emoji {
+                EmojiRendering::Unicode => emoji.clone(),
+                EmojiRendering::Span { class } => {
+                    format!("<span class=\"{}\" title=\":{}:\">{emoji}</span>", html::escape(class), html::escape(name))
+                }
+                EmojiRendering::Image { class, base_url } => format!(
+                    "<img class=\"{}\" title=\":{}:\" alt=\"{emoji}\" src=\"{}\">",
+                    html::escape(class),
+                    html::escape(name),
+                    html::escape_href(&format!("{base_url}{name}.png")),
+                ),
+            },
+        }
+    }
+
+    pub fn children(&self) -> Option<&Vec<Inline>> {
+        match self {
+            Inline::Emphasis(content) |
+            Inline::Strong(content) |
+            Inline::Strikethrough(content) |
+            Inline::Superscript(content) |
+            Inline::Subscript(content) |
+            Inline::Mark(content) |
+            Inline::Inserted(content) |
+            Inline::Link { content, .. } => Some(content),
+            _ => None,
+        }
+    }
+
+    pub fn children_mut(&mut self) -> Option<&mut Vec<Inline>> {
+        match self {
+            Inline::Emphasis(content) |
+            Inline::Strong(content) |
+            Inline::Strikethrough(content) |
+            Inline::Superscript(content) |
+            Inline::Subscript(content) |
+            Inline::Mark(content) |
+            Inline::Inserted(content) |
+            Inline::Link { content, .. } => Some(content),
+            _ => None,
+        }
+    }
+
+    /// Text content without any markup
+    pub fn plain_text(&self) -> String {
+        match self {
+            Inline::Raw(text) |
+            Inline::Text(text) |
+            Inline::Code(text) |
+            Inline::Math { text, .. } => text.clone(),
+            Inline::Html(_) => String::new(),
+            Inline::FootnoteReference { index, .. } => index.to_string(),
+            Inline::Directive(directive) => directive.label.clone().unwrap_or_default(),
+            Inline::WikiLink(link) => link.text(),
+            Inline::Emoji { emoji, .. } => emoji.clone(),
+            Inline::Abbreviation { text, .. } => text.clone(),
+            Inline::Emphasis(content) |
+            Inline::Strong(content) |
+            Inline::Strikethrough(content) |
+            Inline::Superscript(content) |
+            Inline::Subscript(content) |
+            Inline::Mark(content) |
+            Inline::Inserted(content) |
+            Inline::Link { content, .. } => plain_text(content),
+        }
+    }
+}
+
+impl From<&str> for Inline {
+    fn from(value: &str) -> Self {
+        Inline::Text(value.to_string())
+    }
+}
+
+impl From<String> for Inline {
+    fn from(value: String) -> Self {
+        Inline::Text(value)
+    }
+}
+
+pub fn render(content: &[Inline], options: &RenderOptions) -> String {
+    content.iter()
+        .map(|inline| inline.to_html(options))
+        .collect()
+}
+
+pub fn plain_text(content: &[Inline]) -> String {
+    content.iter()
+        .map(Inline::plain_text)
+        .collect()
+}
@@ -1,38 +1,206 @@
+use crate::parser::document::attributes::Attributes;
 use crate::parser::document::block::Block;
+use crate::parser::document::code_block::FencedCode;
+use crate::parser::document::code_info::CodeInfo;
+use crate::parser::document::directive::Directive;
+use crate::parser::document::heading::HeadingMeta;
+use crate::parser::document::html;
+use crate::parser::document::inline::{self, Inline};
+use crate::parser::document::position::Position;
+use crate::parser::options::RenderOptions;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    fn attribute(&self) -> &'static str {
+        match self {
+            Alignment::None => "",
+            Alignment::Left => " align=\"left\"",
+            Alignment::Center => " align=\"center\"",
+            Alignment::Right => " align=\"right\"",
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub enum Leaf {
     ThematicBreak,
     AtxHeading {
         level: usize,
-        text: String,
+        content: Vec<Inline>,
+        meta: HeadingMeta,
     },
     SetextHeading {
         level: usize,
-        text: String,
+        content: Vec<Inline>,
+        meta: HeadingMeta,
     },
     IndentedCodeBlock {
         text: String,
     },
+    /// `info` is the whole info string, its first word is the language. `attributes` of a
+    /// trailing attribute block of the info string are rendered on the `<pre>` element.
     FencedCodeBlock {
         text: String,
         info: Option<String>,
-    }
+        attributes: Attributes,
+    },
+    Paragraph {
+        content: Vec<Inline>,
+    },
+    HtmlBlock {
+        text: String,
+    },
+    /// Fenced code block with a `math` info string, `text` is the raw TeX source
+    MathBlock {
+        text: String,
+    },
+    /// Placeholder replaced by the table of contents of the document when rendering the document
+    TableOfContents,
+    Table {
+        alignments: Vec<Alignment>,
+        header: Vec<Vec<Inline>>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+    /// `::name[label]{attributes}` leaf directive
+    Directive(Directive),
+    /// `*[label]: title` abbreviation definition, taken out of the document by the inline parser
+    AbbreviationDefinition {
+        label: String,
+        title: String,
+    },
 }
 
 impl Leaf {
-    pub fn to_html(&self) -> String {
+    /// Metadata of the info string of a fenced code block
+    pub fn code_info(&self) -> Option<CodeInfo> {
+        match self {
+            Leaf::FencedCodeBlock { info: Some(info), .. } => Some(CodeInfo::parse(info)),
+            _ => None,
+        }
+    }
+
+    /// Inline content of a heading or paragraph, or of every table cell
+    pub fn inlines(&self) -> Vec<&Vec<Inline>> {
+        match self {
+            Leaf::AtxHeading { content, .. } |
+            Leaf::SetextHeading { content, .. } |
+            Leaf::Paragraph { content } => vec![content],
+            Leaf::Table { header, rows, .. } => header.iter().chain(rows.iter().flatten()).collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn inlines_mut(&mut self) -> Vec<&mut Vec<Inline>> {
+        match self {
+            Leaf::AtxHeading { content, .. } |
+            Leaf::SetextHeading { content, .. } |
+            Leaf::Paragraph { content } => vec![content],
+            Leaf::Table { header, rows, .. } => header.iter_mut().chain(rows.iter_mut().flatten()).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Records the source lines of a heading completed on `end_line`
+    ///
+    /// An ATX heading spans a single line, a setext heading spans its content lines followed by
+    /// its underline.
+    pub(crate) fn locate(&mut self, end_line: usize) {
+        match self {
+            Leaf::AtxHeading { meta, .. } => {
+                meta.position = Some(Position { start_line: end_line, end_line });
+            }
+            Leaf::SetextHeading { content, meta, .. } => {
+                let lines = match content.as_slice() {
+                    [Inline::Raw(source)] => source.lines().count().max(1),
+                    _ => 1,
+                };
+
+                meta.position = Some(Position {
+                    start_line: end_line.saturating_sub(lines).max(1),
+                    end_line,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    pub fn to_html(&self, options: &RenderOptions) -> String {
         match self {
             Leaf::ThematicBreak => "<hr />\n".into(),
-            Leaf::AtxHeading { level, text, } |
-            Leaf::SetextHeading { level, text, } => format!("<h{level}>{text}</h{level}>\n"),
+            Leaf::AtxHeading { level, content, meta } |
+            Leaf::SetextHeading { level, content, meta } => {
+                let (id, anchor) = match &meta.id {
+                    Some(id) if options.heading_anchor_links => (
+                        format!(" id=\"{}\"", html::escape(id)),
+                        format!("<a class=\"anchor\" aria-hidden=\"true\" href=\"#{}\"></a>", html::escape_href(id)),
+                    ),
+                    Some(id) => (format!(" id=\"{}\"", html::escape(id)), String::new()),
+                    None => (String::new(), String::new()),
+                };
+
+                format!("<h{level}{id}{}>{anchor}{}</h{level}>\n", meta.attributes.to_html(), inline::render(content, options))
+            }
             Leaf::IndentedCodeBlock { text, } => format!("<pre><code>{text}\n</code></pre>\n"),
-            Leaf::FencedCodeBlock {text, info: Some(info), } => format!("<pre><code class=\"language-{info}\">{text}</code></pre>\n"),
-            Leaf::FencedCodeBlock {text, .. } => format!("<pre><code>{text}</code></pre>\n"),
+            Leaf::FencedCodeBlock {text, info: Some(info), attributes, } => {
+                let metadata = if options.parse_code_info { CodeInfo::parse(info) } else { CodeInfo::unparsed(info) };
+                let language = metadata.language.as_deref().unwrap_or_default();
+                match options.code_block_handlers.get(language) {
+                    Some(handler) => handler(&FencedCode { language, info, text, attributes, metadata: &metadata, }),
+                    None => {
+                        let content = options.highlighter.as_ref()
+                            .and_then(|highlighter| highlighter.highlight(language, text, &metadata))
+                            .unwrap_or_else(|| metadata.wrap_lines(text.split_inclusive('\n')));
+                        match language {
+                            "" => format!("<pre{}><code>{content}</code></pre>\n", attributes.to_html()),
+                            language => format!("<pre{}><code class=\"{}{language}\">{content}</code></pre>\n", attributes.to_html(), options.code_block_language_prefix),
+                        }
+                    }
+                }
+            }
+            Leaf::FencedCodeBlock {text, attributes, .. } => format!("<pre{}><code>{text}</code></pre>\n", attributes.to_html()),
+            Leaf::Paragraph { content, } => format!("<p>{}</p>\n", inline::render(content, options)),
+            Leaf::HtmlBlock { text, } if options.gfm_tagfilter => format!("{}\n", html::filter_tags(text)),
+            Leaf::HtmlBlock { text, } => format!("{text}\n"),
+            Leaf::TableOfContents => String::new(),
+            Leaf::MathBlock { text, } => format!("<div class=\"math display\">{}</div>\n", html::escape(text.trim_end_matches('\n'))),
+            Leaf::Table { alignments, header, rows, } => {
+                let mut out = String::from("<table>\n<thead>\n");
+                out.push_str(&table_row("th", alignments, header, options));
+                out.push_str("</thead>\n");
+
+                if !rows.is_empty() {
+                    out.push_str("<tbody>\n");
+                    for row in rows {
+                        out.push_str(&table_row("td", alignments, row, options));
+                    }
+                    out.push_str("</tbody>\n");
+                }
+
+                out.push_str("</table>\n");
+                out
+            }
+            Leaf::Directive(directive) => directive.to_html(&html::escape(directive.label.as_deref().unwrap_or_default()), options),
+            Leaf::AbbreviationDefinition { .. } => String::new(),
         }
     }
 }
 
+fn table_row(tag: &str, alignments: &[Alignment], cells: &[Vec<Inline>], options: &RenderOptions) -> String {
+    let cells = cells.iter()
+        .zip(alignments)
+        .map(|(cell, alignment)| format!("<{tag}{}>{}</{tag}>\n", alignment.attribute(), inline::render(cell, options)))
+        .collect::<String>();
+
+    format!("<tr>\n{cells}</tr>\n")
+}
+
 impl Into<Block> for Leaf {
     fn into(self) -> Block {
         Block::Leaf(self)
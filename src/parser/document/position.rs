@@ -0,0 +1,7 @@
+/// # Position
+/// Lines of the source spanned by a node, counted from 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub start_line: usize,
+    pub end_line: usize,
+}
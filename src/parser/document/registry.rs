@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::parser::document::shared::Shared;
+
+/// # Registry
+/// Handlers registered by name, such as the renderers of fenced code blocks by language or of
+/// directives by name
+///
+/// Registries are equal when the same handlers are registered for the same names.
+pub struct Registry<F: ?Sized> {
+    handlers: HashMap<String, Shared<F>>,
+}
+
+impl<F: ?Sized> Registry<F> {
+    /// Registers the handler of `name`, replacing a previous one
+    pub fn insert(&mut self, name: impl Into<String>, handler: Arc<F>) {
+        self.handlers.insert(name.into(), Shared::new(handler));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&F> {
+        self.handlers.get(name)
+            .map(Deref::deref)
+    }
+}
+
+impl<F: ?Sized> Default for Registry<F> {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+}
+
+impl<F: ?Sized> Clone for Registry<F> {
+    fn clone(&self) -> Self {
+        Self {
+            handlers: self.handlers.clone(),
+        }
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Registry<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.handlers.keys())
+            .finish()
+    }
+}
+
+impl<F: ?Sized> PartialEq for Registry<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.handlers == other.handlers
+    }
+}
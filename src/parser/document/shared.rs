@@ -0,0 +1,48 @@
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// # Shared
+/// Function held by options, shared by their clones
+///
+/// Functions cannot be compared, shared functions are equal when they are the same function.
+pub struct Shared<F: ?Sized> {
+    function: Arc<F>,
+}
+
+impl<F: ?Sized> Shared<F> {
+    pub fn new(function: Arc<F>) -> Self {
+        Self {
+            function,
+        }
+    }
+}
+
+impl<F: ?Sized> Deref for Shared<F> {
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.function
+    }
+}
+
+impl<F: ?Sized> Clone for Shared<F> {
+    fn clone(&self) -> Self {
+        Self {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Shared<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shared")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: ?Sized> PartialEq for Shared<F> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.function, &other.function)
+    }
+}
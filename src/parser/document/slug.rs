@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+/// GitHub slug of a heading text
+///
+/// The text is lowercased, every character other than a letter, a digit, a hyphen, an
+/// underscore or a space is removed and every space is replaced by a hyphen.
+pub fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter_map(|character| match character {
+            ' ' => Some('-'),
+            '-' | '_' => Some(character),
+            character if character.is_alphanumeric() => Some(character),
+            _ => None,
+        })
+        .collect()
+}
+
+/// # Slugger
+/// Generates unique slugs for the headings of a document
+///
+/// A slug already handed out is suffixed with `-1`, `-2` and so on, skipping suffixed slugs which
+/// are in use themselves.
+#[derive(Debug, Default)]
+pub struct Slugger {
+    occurrences: HashMap<String, usize>,
+}
+
+impl Slugger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks a slug as handed out, generated slugs avoid it
+    pub fn reserve(&mut self, slug: &str) {
+        self.occurrences.entry(slug.to_string()).or_default();
+    }
+
+    pub fn slug(&mut self, text: &str) -> String {
+        let original = slugify(text);
+        let mut slug = original.clone();
+
+        while self.occurrences.contains_key(&slug) {
+            let count = self.occurrences.entry(original.clone()).or_default();
+            *count += 1;
+            slug = format!("{original}-{count}");
+        }
+
+        self.occurrences.insert(slug.clone(), 0);
+        slug
+    }
+}
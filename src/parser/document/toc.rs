@@ -0,0 +1,223 @@
+use crate::parser::document::html;
+use crate::parser::document::inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::position::Position;
+use crate::parser::document::visitor::Visitor;
+use crate::parser::document::Document;
+use crate::parser::options::TocOptions;
+
+/// # Table of contents entry
+/// Heading of the document with the headings of higher levels following it
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    pub level: usize,
+    /// Text of the heading without markup
+    pub text: String,
+    pub id: Option<String>,
+    pub position: Option<Position>,
+    pub children: Vec<TocEntry>,
+}
+
+/// # Table of contents
+/// Headings of a document nested by level
+///
+/// A heading becomes a child of the closest preceding heading of a lower level, skipped levels
+/// do not introduce empty entries.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableOfContents {
+    pub entries: Vec<TocEntry>,
+}
+
+impl TableOfContents {
+    pub fn new(document: &Document, options: &TocOptions) -> Self {
+        let mut headings = Headings {
+            options,
+            table: Self::default(),
+        };
+
+        document.walk(&mut headings);
+        headings.table
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn insert(&mut self, entry: TocEntry) {
+        let mut siblings = &mut self.entries;
+
+        while siblings.last().is_some_and(|last| last.level < entry.level) {
+            siblings = &mut siblings.last_mut().unwrap().children;
+        }
+
+        siblings.push(entry);
+    }
+
+    /// Nested `<ul>` lists linking to the ids of the headings, headings without an id are not
+    /// linked
+    pub fn to_html(&self) -> String {
+        match self.is_empty() {
+            true => String::new(),
+            false => list(&self.entries),
+        }
+    }
+}
+
+fn list(entries: &[TocEntry]) -> String {
+    let items = entries.iter()
+        .map(|entry| {
+            let text = html::escape(&entry.text);
+            let link = match &entry.id {
+                Some(id) => format!("<a href=\"#{}\">{text}</a>", html::escape_href(id)),
+                None => text,
+            };
+
+            match entry.children.is_empty() {
+                true => format!("<li>{link}</li>\n"),
+                false => format!("<li>{link}\n{}</li>\n", list(&entry.children)),
+            }
+        })
+        .collect::<String>();
+
+    format!("<ul>\n{items}</ul>\n")
+}
+
+/// # Headings
+/// Collects the headings within the configured levels
+struct Headings<'a> {
+    options: &'a TocOptions,
+    table: TableOfContents,
+}
+
+impl Visitor for Headings<'_> {
+    fn enter_leaf(&mut self, leaf: &Leaf) {
+        let (Leaf::AtxHeading { level, content, meta } | Leaf::SetextHeading { level, content, meta }) = leaf else {
+            return;
+        };
+
+        if !(self.options.min_level..=self.options.max_level).contains(level) {
+            return;
+        }
+
+        self.table.insert(TocEntry {
+            level: *level,
+            text: inline::plain_text(content),
+            id: meta.id.clone(),
+            position: meta.position,
+            children: vec![],
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::position::Position;
+    use crate::parser::options::{ParseOptions, RenderOptions, TocOptions};
+    use crate::parser::Parser;
+
+    use super::{TableOfContents, TocEntry};
+
+    #[test]
+    /// Table of contents (extension)
+    fn test_table_of_contents() {
+        let document = Parser::from_reader_with_options("# One\n\n### Deep\nTwo\n---\n# Three\n".as_bytes(), ParseOptions { heading_ids: true, ..ParseOptions::gfm() })
+            .parse()
+            .unwrap();
+
+        let entry = |level: usize, text: &str, lines: (usize, usize), children| TocEntry {
+            level,
+            text: text.into(),
+            id: Some(text.to_lowercase()),
+            position: Some(Position { start_line: lines.0, end_line: lines.1 }),
+            children,
+        };
+
+        assert_eq!(
+            TableOfContents {
+                entries: vec![
+                    entry(1, "One", (1, 1), vec![entry(3, "Deep", (3, 3), vec![]), entry(2, "Two", (4, 5), vec![])]),
+                    entry(1, "Three", (6, 6), vec![]),
+                ],
+            },
+            document.table_of_contents(&TocOptions::default())
+        );
+    }
+
+    #[test]
+    /// Table of contents (extension): lines of headings following a front matter or within
+    /// admonitions and directives
+    fn test_table_of_contents_nested_positions() {
+        let options = ParseOptions::gfm().with_constructs(|constructs| {
+            constructs.front_matter = true;
+            constructs.admonition = true;
+            constructs.directive = true;
+        });
+        let document = Parser::from_reader_with_options("---\na: 1\n---\n# One\n\n::: note\n## Two\n\n::: tip\n### Three\n:::\n:::\n::::box\n## Four\n::::\n".as_bytes(), options)
+            .parse()
+            .unwrap();
+
+        fn lines(entries: &[TocEntry]) -> Vec<(String, Option<usize>)> {
+            entries.iter()
+                .flat_map(|entry| [(entry.text.clone(), entry.position.map(|position| position.start_line))].into_iter().chain(lines(&entry.children)))
+                .collect()
+        }
+
+        assert_eq!(
+            vec![
+                ("One".to_string(), Some(4)),
+                ("Two".to_string(), Some(7)),
+                ("Three".to_string(), Some(10)),
+                ("Four".to_string(), Some(14)),
+            ],
+            lines(&document.table_of_contents(&TocOptions::default()).entries)
+        );
+    }
+
+    #[test]
+    /// Table of contents (extension): rendering of a level range
+    fn test_table_of_contents_html() {
+        let document = Parser::from_reader_with_options("# One\n## Two\n### Three\n# Fo<u>r\n".as_bytes(), ParseOptions { heading_ids: true, ..ParseOptions::gfm() })
+            .parse()
+            .unwrap();
+
+        let options = TocOptions {
+            min_level: 1,
+            max_level: 2,
+        };
+
+        assert_eq!(
+            "<ul>\n<li><a href=\"#one\">One</a>\n<ul>\n<li><a href=\"#two\">Two</a></li>\n</ul>\n</li>\n<li><a href=\"#for\">For</a></li>\n</ul>\n",
+            document.table_of_contents(&options).to_html()
+        );
+    }
+
+    #[test]
+    /// Table of contents (extension): placeholders
+    fn test_table_of_contents_placeholder() {
+        let options = ParseOptions {
+            heading_ids: true,
+            ..ParseOptions::gfm().with_constructs(|constructs| constructs.table_of_contents = true)
+        };
+
+        let parser = Parser::from_reader_with_options("[[_TOC_]]\n# One\n## Two\n[TOC] \n[TOC]x\n".as_bytes(), options);
+        let render_options = RenderOptions {
+            table_of_contents: TocOptions {
+                min_level: 2,
+                max_level: 6,
+            },
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<ul>\n<li><a href=\"#two\">Two</a></li>\n</ul>\n<h1 id=\"one\">One</h1>\n<h2 id=\"two\">Two</h2>\n<ul>\n<li><a href=\"#two\">Two</a></li>\n</ul>\n",
+            parser.parse_to_string_with(&render_options).unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Table of contents (extension): placeholders disabled by default
+    fn test_table_of_contents_placeholder_disabled() {
+        let parser = Parser::from_reader_with_options("[TOC]\n\n# One\n".as_bytes(), ParseOptions { heading_ids: true, ..ParseOptions::gfm() });
+        assert_eq!("<h1 id=\"one\">One</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
@@ -0,0 +1,400 @@
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::document::front_matter::FrontMatter;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::Document;
+
+/// # Node id
+/// Handle of a [Node] inside of a [Tree]
+///
+/// Ids are only meaningful for the tree that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone)]
+pub enum NodeValue {
+    Document,
+    Container(ContainerKind),
+    Leaf(Leaf),
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    value: NodeValue,
+    parent: Option<NodeId>,
+    first_child: Option<NodeId>,
+    last_child: Option<NodeId>,
+    previous_sibling: Option<NodeId>,
+    next_sibling: Option<NodeId>,
+}
+
+impl Node {
+    fn new(value: NodeValue, parent: Option<NodeId>) -> Self {
+        Self {
+            value,
+            parent,
+            first_child: None,
+            last_child: None,
+            previous_sibling: None,
+            next_sibling: None,
+        }
+    }
+
+    pub fn value(&self) -> &NodeValue {
+        &self.value
+    }
+
+    pub fn value_mut(&mut self) -> &mut NodeValue {
+        &mut self.value
+    }
+}
+
+/// # Tree
+/// Arena backed representation of a [Document]
+///
+/// All nodes live in a single vector and reference each other by [NodeId], which makes parent and
+/// sibling navigation cheap. The root node is always a [NodeValue::Document], the front matter of
+/// the document is kept alongside the nodes.
+#[derive(Debug, Clone)]
+pub struct Tree {
+    nodes: Vec<Node>,
+    front_matter: Option<FrontMatter>,
+}
+
+impl Tree {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::new(NodeValue::Document, None)],
+            front_matter: None,
+        }
+    }
+
+    pub fn front_matter(&self) -> Option<&FrontMatter> {
+        self.front_matter.as_ref()
+    }
+
+    pub fn set_front_matter(&mut self, front_matter: Option<FrontMatter>) {
+        self.front_matter = front_matter;
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn get(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id.0]
+    }
+
+    pub fn node(&self, id: NodeId) -> NodeRef<'_> {
+        NodeRef {
+            tree: self,
+            id,
+        }
+    }
+
+    /// Appends a new node as last child of `parent`
+    pub fn append(&mut self, parent: NodeId, value: NodeValue) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        let mut node = Node::new(value, Some(parent));
+        node.previous_sibling = self.get(parent).last_child;
+
+        match node.previous_sibling {
+            Some(previous) => self.get_mut(previous).next_sibling = Some(id),
+            None => self.get_mut(parent).first_child = Some(id),
+        }
+
+        self.get_mut(parent).last_child = Some(id);
+        self.nodes.push(node);
+
+        id
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.get(id).parent
+    }
+
+    pub fn first_child(&self, id: NodeId) -> Option<NodeId> {
+        self.get(id).first_child
+    }
+
+    pub fn last_child(&self, id: NodeId) -> Option<NodeId> {
+        self.get(id).last_child
+    }
+
+    pub fn next_sibling(&self, id: NodeId) -> Option<NodeId> {
+        self.get(id).next_sibling
+    }
+
+    pub fn previous_sibling(&self, id: NodeId) -> Option<NodeId> {
+        self.get(id).previous_sibling
+    }
+
+    pub fn children(&self, id: NodeId) -> Children<'_> {
+        Children {
+            tree: self,
+            next: self.first_child(id),
+        }
+    }
+
+    /// Siblings preceding `id`, nearest first
+    pub fn preceding_siblings(&self, id: NodeId) -> PrecedingSiblings<'_> {
+        PrecedingSiblings {
+            tree: self,
+            next: self.previous_sibling(id),
+        }
+    }
+
+    /// Ancestors of `id`, parent first and root last
+    pub fn ancestors(&self, id: NodeId) -> Ancestors<'_> {
+        Ancestors {
+            tree: self,
+            next: self.parent(id),
+        }
+    }
+
+    /// Descendants of `id` in document order, excluding `id` itself
+    pub fn descendants(&self, id: NodeId) -> Descendants<'_> {
+        Descendants {
+            tree: self,
+            root: id,
+            next: self.first_child(id),
+        }
+    }
+
+    fn append_block(&mut self, parent: NodeId, block: Block) {
+        match block {
+            Block::Container(container) => {
+                let id = self.append(parent, NodeValue::Container(container.kind));
+
+                for block in container.content {
+                    self.append_block(id, block);
+                }
+            }
+            Block::Leaf(leaf) => {
+                self.append(parent, NodeValue::Leaf(leaf));
+            }
+        }
+    }
+
+    fn to_block(&self, id: NodeId) -> Option<Block> {
+        match self.get(id).value() {
+            NodeValue::Document => None,
+            NodeValue::Container(kind) => Some(
+                Container::new(
+                    kind.clone(),
+                    self.children(id)
+                        .filter_map(|child| self.to_block(child))
+                        .collect(),
+                ).into()
+            ),
+            NodeValue::Leaf(leaf) => Some(leaf.clone().into()),
+        }
+    }
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Document> for Tree {
+    fn from(value: Document) -> Self {
+        let mut tree = Tree::new();
+        let root = tree.root();
+        tree.set_front_matter(value.front_matter().cloned());
+
+        for block in value.into_blocks() {
+            tree.append_block(root, block);
+        }
+
+        tree
+    }
+}
+
+impl From<&Tree> for Document {
+    fn from(value: &Tree) -> Self {
+        let mut document = Document::new();
+        document.set_front_matter(value.front_matter().cloned());
+
+        value.children(value.root())
+            .filter_map(|id| value.to_block(id))
+            .for_each(|block| document.push(block));
+
+        document
+    }
+}
+
+/// # Node reference
+/// Copyable handle combining a [Tree] with a [NodeId]
+#[derive(Clone, Copy)]
+pub struct NodeRef<'a> {
+    tree: &'a Tree,
+    id: NodeId,
+}
+
+impl<'a> NodeRef<'a> {
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn value(&self) -> &'a NodeValue {
+        self.tree.get(self.id).value()
+    }
+
+    pub fn parent(&self) -> Option<NodeRef<'a>> {
+        self.wrap(self.tree.parent(self.id))
+    }
+
+    pub fn first_child(&self) -> Option<NodeRef<'a>> {
+        self.wrap(self.tree.first_child(self.id))
+    }
+
+    pub fn last_child(&self) -> Option<NodeRef<'a>> {
+        self.wrap(self.tree.last_child(self.id))
+    }
+
+    pub fn next_sibling(&self) -> Option<NodeRef<'a>> {
+        self.wrap(self.tree.next_sibling(self.id))
+    }
+
+    pub fn previous_sibling(&self) -> Option<NodeRef<'a>> {
+        self.wrap(self.tree.previous_sibling(self.id))
+    }
+
+    pub fn children(&self) -> impl Iterator<Item=NodeRef<'a>> {
+        let tree = self.tree;
+        tree.children(self.id).map(move |id| tree.node(id))
+    }
+
+    pub fn preceding_siblings(&self) -> impl Iterator<Item=NodeRef<'a>> {
+        let tree = self.tree;
+        tree.preceding_siblings(self.id).map(move |id| tree.node(id))
+    }
+
+    pub fn ancestors(&self) -> impl Iterator<Item=NodeRef<'a>> {
+        let tree = self.tree;
+        tree.ancestors(self.id).map(move |id| tree.node(id))
+    }
+
+    pub fn descendants(&self) -> impl Iterator<Item=NodeRef<'a>> {
+        let tree = self.tree;
+        tree.descendants(self.id).map(move |id| tree.node(id))
+    }
+
+    fn wrap(&self, id: Option<NodeId>) -> Option<NodeRef<'a>> {
+        id.map(|id| self.tree.node(id))
+    }
+}
+
+pub struct Children<'a> {
+    tree: &'a Tree,
+    next: Option<NodeId>,
+}
+
+impl Iterator for Children<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = self.tree.next_sibling(current);
+        Some(current)
+    }
+}
+
+pub struct PrecedingSiblings<'a> {
+    tree: &'a Tree,
+    next: Option<NodeId>,
+}
+
+impl Iterator for PrecedingSiblings<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = self.tree.previous_sibling(current);
+        Some(current)
+    }
+}
+
+pub struct Ancestors<'a> {
+    tree: &'a Tree,
+    next: Option<NodeId>,
+}
+
+impl Iterator for Ancestors<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = self.tree.parent(current);
+        Some(current)
+    }
+}
+
+pub struct Descendants<'a> {
+    tree: &'a Tree,
+    root: NodeId,
+    next: Option<NodeId>,
+}
+
+impl Iterator for Descendants<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        self.next = self.tree.first_child(current)
+            .or_else(|| {
+                let mut node = current;
+                loop {
+                    if node == self.root {
+                        return None;
+                    }
+                    if let Some(sibling) = self.tree.next_sibling(node) {
+                        return Some(sibling);
+                    }
+                    node = self.tree.parent(node)?;
+                }
+            });
+
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::document::Document;
+    use crate::parser::Parser;
+
+    use super::{NodeValue, Tree};
+
+    #[test]
+    /// Tree: parent and sibling navigation
+    fn test_tree_navigation() {
+        let document = Parser::from_reader("# foo
+***
+    bar
+".as_bytes()).parse().unwrap();
+        let tree = Tree::from(document);
+        let root = tree.node(tree.root());
+
+        let code = root.last_child().unwrap();
+        assert!(matches!(code.value(), NodeValue::Leaf(Leaf::IndentedCodeBlock { .. })));
+        assert_eq!(Some(root.id()), code.parent().map(|parent| parent.id()));
+
+        let heading = code.preceding_siblings()
+            .find(|node| matches!(node.value(), NodeValue::Leaf(Leaf::AtxHeading { .. })))
+            .unwrap();
+        assert_eq!(root.first_child().map(|child| child.id()), Some(heading.id()));
+        assert_eq!(3, root.descendants().count());
+        assert_eq!(vec![root.id()], code.ancestors().map(|node| node.id()).collect::<Vec<_>>());
+
+        assert_eq!("<h1>foo</h1>\n<hr />\n<pre><code>bar\n</code></pre>\n", Document::from(&tree).to_string());
+    }
+}
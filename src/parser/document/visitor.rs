@@ -0,0 +1,237 @@
+use std::mem;
+
+use crate::parser::document::block::Block;
+use crate::parser::document::container::Container;
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::Document;
+
+/// # Visitor
+/// Read-only traversal of a [Document]
+///
+/// Every callback defaults to doing nothing, so implementors only override the node kinds they
+/// are interested in.
+///
+/// ## Order
+/// * [Visitor::enter_document] before the first block
+/// * [Visitor::enter_block] and [Visitor::exit_block] around every block
+/// * [Visitor::enter_container] and [Visitor::exit_container] around every container and its
+///   content, nested in its block
+/// * [Visitor::enter_leaf] and [Visitor::exit_leaf] around every leaf, nested in its block
+/// * [Visitor::enter_inline] and [Visitor::exit_inline] around every inline and its children,
+///   nested in its leaf
+/// * [Visitor::exit_document] after the last block
+pub trait Visitor {
+    fn enter_document(&mut self, _document: &Document) {}
+    fn exit_document(&mut self, _document: &Document) {}
+    fn enter_block(&mut self, _block: &Block) {}
+    fn exit_block(&mut self, _block: &Block) {}
+    fn enter_container(&mut self, _container: &Container) {}
+    fn exit_container(&mut self, _container: &Container) {}
+    fn enter_leaf(&mut self, _leaf: &Leaf) {}
+    fn exit_leaf(&mut self, _leaf: &Leaf) {}
+    fn enter_inline(&mut self, _inline: &Inline) {}
+    fn exit_inline(&mut self, _inline: &Inline) {}
+}
+
+/// # Mutable visitor
+/// Owning traversal of a [Document] able to restructure it
+///
+/// Every block is handed over to [VisitorMut::fold_block] and replaced by the returned blocks:
+/// * an empty vector removes the block
+/// * a single block keeps or replaces it
+/// * multiple blocks insert the additional blocks in place
+///
+/// The default [VisitorMut::fold_block] hands containers over to [VisitorMut::fold_container],
+/// which folds their content, and leaves over to [VisitorMut::fold_leaf], which folds their
+/// inlines. Inlines are replaced by the inlines returned from [VisitorMut::fold_inline] the same
+/// way, its default folds their children and keeps them.
+pub trait VisitorMut {
+    fn enter_document(&mut self, _document: &mut Document) {}
+    fn exit_document(&mut self, _document: &mut Document) {}
+
+    fn fold_block(&mut self, block: Block) -> Vec<Block> {
+        match block {
+            Block::Container(container) => self.fold_container(container),
+            Block::Leaf(leaf) => self.fold_leaf(leaf),
+        }
+    }
+
+    fn fold_container(&mut self, mut container: Container) -> Vec<Block> {
+        container.content = fold_blocks(self, container.content);
+        vec![container.into()]
+    }
+
+    fn fold_leaf(&mut self, mut leaf: Leaf) -> Vec<Block> {
+        for content in leaf.inlines_mut() {
+            *content = fold_inlines(self, mem::take(content));
+        }
+
+        vec![leaf.into()]
+    }
+
+    fn fold_inline(&mut self, mut inline: Inline) -> Vec<Inline> {
+        if let Some(children) = inline.children_mut() {
+            *children = fold_inlines(self, mem::take(children));
+        }
+
+        vec![inline]
+    }
+}
+
+pub(crate) fn walk_block<V: Visitor + ?Sized>(visitor: &mut V, block: &Block) {
+    visitor.enter_block(block);
+
+    match block {
+        Block::Container(container) => {
+            visitor.enter_container(container);
+
+            for block in &container.content {
+                walk_block(visitor, block);
+            }
+
+            visitor.exit_container(container);
+        }
+        Block::Leaf(leaf) => {
+            visitor.enter_leaf(leaf);
+
+            for inline in leaf.inlines().into_iter().flatten() {
+                walk_inline(visitor, inline);
+            }
+
+            visitor.exit_leaf(leaf);
+        }
+    }
+
+    visitor.exit_block(block);
+}
+
+fn walk_inline<V: Visitor + ?Sized>(visitor: &mut V, inline: &Inline) {
+    visitor.enter_inline(inline);
+
+    for child in inline.children().into_iter().flatten() {
+        walk_inline(visitor, child);
+    }
+
+    visitor.exit_inline(inline);
+}
+
+pub(crate) fn fold_blocks<V: VisitorMut + ?Sized>(visitor: &mut V, blocks: Vec<Block>) -> Vec<Block> {
+    blocks.into_iter()
+        .flat_map(|block| visitor.fold_block(block))
+        .collect()
+}
+
+pub(crate) fn fold_inlines<V: VisitorMut + ?Sized>(visitor: &mut V, inlines: Vec<Inline>) -> Vec<Inline> {
+    inlines.into_iter()
+        .flat_map(|inline| visitor.fold_inline(inline))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use crate::parser::document::block::Block;
+    use crate::parser::document::inline::Inline;
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    use super::{Visitor, VisitorMut};
+
+    #[test]
+    /// Visitor: enter and exit order
+    fn test_visitor_order() {
+        struct Recorder(Vec<String>);
+
+        impl Visitor for Recorder {
+            fn enter_leaf(&mut self, leaf: &Leaf) {
+                self.0.push(format!("enter {}", leaf.to_html(&RenderOptions::default()).trim_end()));
+            }
+
+            fn exit_leaf(&mut self, _: &Leaf) {
+                self.0.push("exit".into());
+            }
+        }
+
+        let document = Parser::from_reader("# foo\n***\n".as_bytes()).parse().unwrap();
+        let mut recorder = Recorder(vec![]);
+        document.walk(&mut recorder);
+
+        assert_eq!(vec!["enter <h1>foo</h1>", "exit", "enter <hr />", "exit"], recorder.0);
+    }
+
+    #[test]
+    /// VisitorMut: replace, remove and insert
+    fn test_visitor_mut_fold() {
+        struct Transformer;
+
+        impl VisitorMut for Transformer {
+            fn fold_leaf(&mut self, leaf: Leaf) -> Vec<Block> {
+                match leaf {
+                    Leaf::AtxHeading { level, content, meta } => vec![
+                        Leaf::AtxHeading { level: level + 1, content, meta }.into(),
+                        Leaf::ThematicBreak.into(),
+                    ],
+                    Leaf::IndentedCodeBlock { .. } => vec![],
+                    leaf => vec![leaf.into()],
+                }
+            }
+        }
+
+        let mut document = Parser::from_reader("# foo\n\n    bar\n".as_bytes()).parse().unwrap();
+        document.walk_mut(&mut Transformer);
+
+        assert_eq!("<h2>foo</h2>\n<hr />\n", document.to_string());
+    }
+
+    #[test]
+    /// Visitor: inlines nested in their leaf
+    fn test_visitor_inlines() {
+        struct Recorder(Vec<String>);
+
+        impl Visitor for Recorder {
+            fn enter_inline(&mut self, inline: &Inline) {
+                self.0.push(inline.to_html(&RenderOptions::default()));
+            }
+        }
+
+        let document = Parser::from_reader_with_options("# *foo <b>bar</b>*\n".as_bytes(), ParseOptions::gfm()).parse().unwrap();
+        let mut recorder = Recorder(vec![]);
+        document.walk(&mut recorder);
+
+        assert_eq!(vec!["<em>foo <b>bar</b></em>", "foo ", "<b>", "bar", "</b>"], recorder.0);
+    }
+
+    #[test]
+    /// VisitorMut: rewrite link destinations and remove raw HTML
+    fn test_visitor_mut_inlines() {
+        struct Sanitizer;
+
+        impl VisitorMut for Sanitizer {
+            fn fold_inline(&mut self, mut inline: Inline) -> Vec<Inline> {
+                if let Some(children) = inline.children_mut() {
+                    *children = mem::take(children).into_iter()
+                        .flat_map(|child| self.fold_inline(child))
+                        .collect();
+                }
+
+                match inline {
+                    Inline::Link { destination, title, content } => vec![Inline::Link {
+                        destination: destination.replacen("http://", "https://", 1),
+                        title,
+                        content,
+                    }],
+                    Inline::Html(_) => vec![],
+                    inline => vec![inline],
+                }
+            }
+        }
+
+        let mut document = Parser::from_reader_with_options("# www.example.com *<b>bar</b>*\n".as_bytes(), ParseOptions::gfm()).parse().unwrap();
+        document.walk_mut(&mut Sanitizer);
+
+        assert_eq!("<h1><a href=\"https://www.example.com\">www.example.com</a> <em>bar</em></h1>\n", document.to_string());
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::parser::document::html;
+use crate::parser::document::shared::Shared;
+use crate::parser::document::slug;
+use crate::parser::options::RenderOptions;
+
+/// # Wiki link
+/// `[[Target]]`, `[[Target#Section]]` or `[[Target|Label]]` link to a page of a wiki
+///
+/// `target` is empty for `[[#Section]]` links within the same page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WikiLink {
+    pub target: String,
+    pub section: Option<String>,
+    pub label: Option<String>,
+}
+
+impl WikiLink {
+    /// Label of the link, the target and section as written unless a label is given
+    pub fn text(&self) -> String {
+        match (&self.label, &self.section) {
+            (Some(label), _) => label.clone(),
+            (None, Some(section)) => format!("{}#{section}", self.target),
+            (None, None) => self.target.clone(),
+        }
+    }
+
+    /// Target followed by the GitHub slug of the section
+    pub fn default_url(&self) -> String {
+        match &self.section {
+            Some(section) => format!("{}#{}", self.target, slug::slugify(section)),
+            None => self.target.clone(),
+        }
+    }
+
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        let url = match &options.wiki_link_resolver {
+            Some(resolver) => resolver.resolve(self),
+            None => Some(self.default_url()),
+        };
+
+        match url {
+            Some(url) => format!("<a href=\"{}\" class=\"wikilink\">{}</a>", html::escape_href(&url), html::escape(&self.text())),
+            None => format!("<a class=\"wikilink {}\">{}</a>", html::escape(&options.wiki_link_broken_class), html::escape(&self.text())),
+        }
+    }
+}
+
+/// URL of the page of a wiki link, [None] if the page does not exist
+pub type Resolve = dyn Fn(&WikiLink) -> Option<String> + Send + Sync;
+
+/// # Wiki link resolver
+/// Maps wiki links to the URLs of their pages, links resolved to [None] are broken
+#[derive(Debug, Clone, PartialEq)]
+pub struct WikiLinkResolver {
+    resolve: Shared<Resolve>,
+}
+
+impl WikiLinkResolver {
+    pub fn new(resolve: impl Fn(&WikiLink) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self {
+            resolve: Shared::new(Arc::new(resolve)),
+        }
+    }
+
+    pub fn resolve(&self, link: &WikiLink) -> Option<String> {
+        (*self.resolve)(link)
+    }
+}
@@ -0,0 +1,158 @@
+//! Recognition of a front matter block at the very start of the input
+
+use crate::parser::document::front_matter::{FrontMatter, FrontMatterKind};
+
+/// Marker ending a YAML document, closing a YAML front matter like its delimiter
+const YAML_DOCUMENT_END: &str = "...";
+
+/// Splits a leading front matter block off the source
+///
+/// The block starts with a `---` or `+++` line as the first line of the source and ends with the
+/// next line consisting of the same delimiter, or of the `...` document end marker for YAML.
+/// Trailing spaces and tabs are allowed on both lines. Without a closing line the source has no
+/// front matter. Returns the front matter and the remaining source following the closing line.
+pub fn split(source: &str) -> Option<(FrontMatter, &str)> {
+    let kind = [FrontMatterKind::Yaml, FrontMatterKind::Toml].into_iter()
+        .find(|kind| source.starts_with(kind.delimiter()))?;
+
+    let mut lines = source.split_inclusive('\n');
+    let opening = lines.next()?;
+    if !is_delimiter(opening, kind) || !opening.ends_with('\n') {
+        return None;
+    }
+
+    let start = opening.len();
+    let mut end = start;
+
+    for line in lines {
+        if is_closing(line, kind) {
+            let raw = source[start..end].strip_suffix('\n').unwrap_or(&source[start..end]);
+            let raw = raw.strip_suffix('\r').unwrap_or(raw);
+
+            return Some((FrontMatter::new(kind, raw), &source[end + line.len()..]));
+        }
+        end += line.len();
+    }
+
+    None
+}
+
+fn is_delimiter(line: &str, kind: FrontMatterKind) -> bool {
+    trim_line(line) == kind.delimiter()
+}
+
+fn is_closing(line: &str, kind: FrontMatterKind) -> bool {
+    is_delimiter(line, kind) || (kind == FrontMatterKind::Yaml && trim_line(line) == YAML_DOCUMENT_END)
+}
+
+fn trim_line(line: &str) -> &str {
+    line.trim_end_matches(['\n', '\r'])
+        .trim_end_matches([' ', '\t'])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::front_matter::{FrontMatter, FrontMatterKind};
+    use crate::parser::document::tree::Tree;
+    use crate::parser::document::Document;
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Front matter (extension): YAML
+    fn test_front_matter_yaml() {
+        let document = Parser::from_reader_with_options("---\ntitle: Hello\ntags: [a, b]\n---\n# Body\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        assert_eq!(Some(&FrontMatter::new(FrontMatterKind::Yaml, "title: Hello\ntags: [a, b]")), document.front_matter());
+        assert_eq!("<h1>Body</h1>\n", document.to_string());
+    }
+
+    #[test]
+    /// Front matter (extension): TOML
+    fn test_front_matter_toml() {
+        let document = Parser::from_reader_with_options("+++\ntitle = \"Hello\"\n+++\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        assert_eq!(Some(&FrontMatter::new(FrontMatterKind::Toml, "title = \"Hello\"")), document.front_matter());
+        assert_eq!("", document.to_string());
+    }
+
+    #[test]
+    /// Front matter (extension): YAML closed by the document end marker
+    fn test_front_matter_yaml_document_end() {
+        let document = Parser::from_reader_with_options("---\na: 1\n...\n# B\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        assert_eq!(Some(&FrontMatter::new(FrontMatterKind::Yaml, "a: 1")), document.front_matter());
+        assert_eq!("<h1>B</h1>\n", document.to_string());
+
+        let document = Parser::from_reader_with_options("+++\na = 1\n...\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        assert_eq!(None, document.front_matter());
+    }
+
+    #[test]
+    /// Front matter (extension): following a byte order mark
+    fn test_front_matter_byte_order_mark() {
+        let document = Parser::from_reader_with_options("\u{FEFF}---\na: 1\n---\n# B\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        assert_eq!(Some(&FrontMatter::new(FrontMatterKind::Yaml, "a: 1")), document.front_matter());
+        assert_eq!("<h1>B</h1>\n", document.to_string());
+    }
+
+    #[test]
+    /// Front matter (extension): kept by a tree round trip
+    fn test_front_matter_tree() {
+        let document = Parser::from_reader_with_options("---\na: 1\n---\n# B\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        let tree = Tree::from(document);
+        assert_eq!(Some(&FrontMatter::new(FrontMatterKind::Yaml, "a: 1")), tree.front_matter());
+
+        let document = Document::from(&tree);
+        assert_eq!(Some(&FrontMatter::new(FrontMatterKind::Yaml, "a: 1")), document.front_matter());
+        assert_eq!("<h1>B</h1>\n", document.to_string());
+    }
+
+    #[test]
+    /// Front matter (extension): only at the start of the document
+    fn test_front_matter_only_at_start() {
+        let document = Parser::from_reader_with_options("\n---\ntitle: Hello\n---\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        assert_eq!(None, document.front_matter());
+        assert_eq!("<hr />\n<h2>title: Hello</h2>\n", document.to_string());
+    }
+
+    #[test]
+    /// Front matter (extension): unclosed fences are no front matter
+    fn test_front_matter_unclosed() {
+        let document = Parser::from_reader_with_options("---\n# Body\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.front_matter = true))
+            .parse()
+            .unwrap();
+
+        assert_eq!(None, document.front_matter());
+        assert_eq!("<hr />\n<h1>Body</h1>\n", document.to_string());
+    }
+
+    #[test]
+    /// Front matter (extension): disabled by default
+    fn test_front_matter_disabled() {
+        let document = Parser::from_reader("---\ntitle: Hello\n---\n".as_bytes())
+            .parse()
+            .unwrap();
+
+        assert_eq!(None, document.front_matter());
+        assert_eq!("<hr />\n<h2>title: Hello</h2>\n", document.to_string());
+    }
+}
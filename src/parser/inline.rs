@@ -0,0 +1,532 @@
+mod abbreviation;
+mod autolink;
+mod delimiter;
+mod emoji;
+mod footnote;
+mod math;
+mod nodes;
+mod smart;
+mod task_list;
+mod wiki_link;
+
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+use crate::parser::document::block::Block;
+use crate::parser::document::container::Container;
+use crate::parser::document::directive::DirectiveKind;
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::visitor::VisitorMut;
+use crate::parser::document::Document;
+use crate::parser::directive::{self, COLON};
+use crate::parser::inline::abbreviation::Abbreviations;
+use crate::parser::inline::delimiter::{DelimiterRun, DelimiterStack, ASTERISK, CARET, EQUALS, PLUS, TILDE, UNDERSCORE};
+use crate::parser::inline::footnote::FootnoteDefinitions;
+use crate::parser::inline::math::{MathClosers, DOLLAR};
+use crate::parser::inline::nodes::{Node, Nodes};
+use crate::parser::inline::smart::{DOUBLE_QUOTE, ELLIPSIS, ELLIPSIS_LENGTH, HYPHEN, PERIOD, SINGLE_QUOTE};
+use crate::parser::options::ParseOptions;
+use crate::parser::raw_html::{self, Unterminated};
+use crate::unicode;
+
+const BACKTICK: char = '`';
+const AT: char = '@';
+const LESS_THAN: char = '<';
+const OPENING_BRACKET: char = '[';
+const CLOSING_BRACKET: char = ']';
+
+/// Replaces the [Inline::Raw] content of every leaf of the document by its parsed inline nodes
+///
+/// Footnote definitions are taken out of the document first, so references to them can be
+/// recognized anywhere, and are appended again once their references are numbered. Abbreviation
+/// definitions are taken out as well and apply to the text of the whole document.
+pub fn parse_document(document: &mut Document, options: &ParseOptions) {
+    let mut footnotes = match options.constructs.gfm_footnote_definition {
+        true => FootnoteDefinitions::extract(document, options.limits.max_reference_definitions),
+        false => FootnoteDefinitions::default(),
+    };
+
+    let abbreviations = match options.constructs.abbreviation {
+        true => Abbreviations::extract(document, options.limits.max_reference_definitions),
+        false => Abbreviations::default(),
+    };
+
+    let footnote_labels = footnotes.labels();
+    let mut pass = InlinePass {
+        options,
+        footnote_labels: &footnote_labels,
+        abbreviations: &abbreviations,
+    };
+
+    document.walk_mut(&mut pass);
+    footnotes.fold(&mut pass);
+    footnotes.resolve(document);
+}
+
+/// # Inline pass
+/// Runs the inline parser over the raw content of headings, paragraphs and table cells once the
+/// block structure of the document is known
+struct InlinePass<'a> {
+    options: &'a ParseOptions,
+    footnote_labels: &'a HashSet<String>,
+    abbreviations: &'a Abbreviations,
+}
+
+impl InlinePass<'_> {
+    fn resolve(&self, content: &mut Vec<Inline>) {
+        *content = mem::take(content).into_iter()
+            .flat_map(|inline| match inline {
+                Inline::Raw(source) => InlineParser::new(&source, self.options, self.footnote_labels).parse(),
+                inline => vec![inline],
+            })
+            .collect();
+
+        self.abbreviations.apply(content);
+    }
+}
+
+impl VisitorMut for InlinePass<'_> {
+    fn fold_container(&mut self, mut container: Container) -> Vec<Block> {
+        container.content = container.content.into_iter()
+            .flat_map(|block| self.fold_block(block))
+            .collect();
+
+        if self.options.constructs.gfm_task_list_item {
+            task_list::resolve(&mut container);
+        }
+
+        vec![container.into()]
+    }
+
+    fn fold_leaf(&mut self, mut leaf: Leaf) -> Vec<Block> {
+        for content in leaf.inlines_mut() {
+            self.resolve(content);
+        }
+
+        vec![leaf.into()]
+    }
+}
+
+/// # Inline parser
+/// Resolves the raw source of a single block into [Inline] nodes
+///
+/// The source is scanned once from left to right. Backslash escapes, code spans, raw HTML, literal
+/// autolinks and footnote references are resolved while scanning, delimiter runs are collected on
+/// a [DelimiterStack] and matched into emphasis and strikethrough after the scan.
+pub struct InlineParser<'a> {
+    options: &'a ParseOptions,
+    /// Normalized labels of the footnote definitions of the document
+    footnote_labels: &'a HashSet<String>,
+    source: Vec<char>,
+    position: usize,
+    text: String,
+    nodes: Nodes,
+    delimiters: DelimiterStack,
+    code_span_closers: Option<CodeSpanClosers>,
+    math_closers: Option<MathClosers>,
+    unterminated_html: Unterminated,
+}
+
+impl<'a> InlineParser<'a> {
+    pub fn new(source: &str, options: &'a ParseOptions, footnote_labels: &'a HashSet<String>) -> Self {
+        Self {
+            options,
+            footnote_labels,
+            source: source.chars().collect(),
+            position: 0,
+            text: String::new(),
+            nodes: Nodes::new(),
+            delimiters: DelimiterStack::new(options.constructs.subscript),
+            code_span_closers: None,
+            math_closers: None,
+            unterminated_html: Unterminated::default(),
+        }
+    }
+
+    pub fn parse(mut self) -> Vec<Inline> {
+        let constructs = &self.options.constructs;
+
+        while let Some(&character) = self.source.get(self.position) {
+            match character {
+                unicode::BACKSLASH => self.escape(),
+                BACKTICK => self.code_span(),
+                DOLLAR if constructs.math_text => self.math(),
+                ASTERISK | UNDERSCORE => self.delimiter_run(character),
+                TILDE if constructs.gfm_strikethrough || constructs.subscript => self.delimiter_run(character),
+                CARET if constructs.superscript => self.delimiter_run(character),
+                EQUALS if constructs.mark => self.delimiter_run(character),
+                PLUS if constructs.inserted => self.delimiter_run(character),
+                LESS_THAN if constructs.html_text => self.raw_html(),
+                OPENING_BRACKET if constructs.wiki_link && self.run_length(OPENING_BRACKET) >= 2 => self.wiki_link(),
+                OPENING_BRACKET if constructs.gfm_footnote_reference => self.footnote_reference(),
+                AT if constructs.gfm_autolink_literal => self.email_autolink(),
+                COLON if constructs.emoji => self.emoji(),
+                COLON if constructs.directive => self.directive(),
+                'w' | 'h' | 'H' if constructs.gfm_autolink_literal => self.url_autolink(character),
+                SINGLE_QUOTE | DOUBLE_QUOTE if self.options.smart_punctuation => self.quote(character),
+                HYPHEN if self.options.smart_punctuation => self.dashes(),
+                PERIOD if self.options.smart_punctuation => self.ellipsis(),
+                character => self.literal(character, 1),
+            }
+        }
+
+        self.flush();
+        self.delimiters.process(&mut self.nodes);
+        self.nodes.into_inlines()
+    }
+
+    fn previous(&self) -> Option<char> {
+        self.position.checked_sub(1)
+            .map(|index| self.source[index])
+    }
+
+    fn literal(&mut self, character: char, length: usize) {
+        for _ in 0..length {
+            self.text.push(character);
+        }
+        self.position += length;
+    }
+
+    fn flush(&mut self) {
+        if !self.text.is_empty() {
+            self.nodes.push_inline(Inline::Text(mem::take(&mut self.text)));
+        }
+    }
+
+    fn push(&mut self, inline: Inline) {
+        self.flush();
+        self.nodes.push_inline(inline);
+    }
+
+    fn run_length(&self, character: char) -> usize {
+        self.source[self.position..].iter()
+            .take_while(|next| **next == character)
+            .count()
+    }
+
+    fn escape(&mut self) {
+        match self.source.get(self.position + 1) {
+            Some(character) if unicode::escapable(character) => {
+                self.text.push(*character);
+                self.position += 2;
+            }
+            _ => self.literal(unicode::BACKSLASH, 1),
+        }
+    }
+
+    /// A code span closes at the next backtick run of the same length, otherwise the backticks are
+    /// literal
+    fn code_span(&mut self) {
+        let length = self.run_length(BACKTICK);
+        let start = self.position + length;
+
+        let closers = self.code_span_closers.get_or_insert_with(|| CodeSpanClosers::new(&self.source));
+        let Some(end) = closers.next(length, start) else {
+            return self.literal(BACKTICK, length);
+        };
+
+        let code = self.source[start..end].iter()
+            .map(|character| match character {
+                &unicode::LINE_FEED => unicode::SPACE,
+                character => *character,
+            })
+            .collect::<String>();
+
+        let stripped = code.len() >= 2
+            && code.starts_with(unicode::SPACE)
+            && code.ends_with(unicode::SPACE)
+            && code.chars().any(|character| character != unicode::SPACE);
+
+        let code = match stripped {
+            true => code[1..code.len() - 1].to_string(),
+            false => code,
+        };
+
+        self.push(Inline::Code(code));
+        self.position = end + length;
+    }
+
+    /// `$inline$` or `$$display$$` math, the content is kept raw
+    fn math(&mut self) {
+        let length = self.run_length(DOLLAR);
+        let start = self.position + length;
+
+        let opens = match length {
+            1 => math::can_open_inline(&self.source, self.position),
+            2 => true,
+            _ => false,
+        };
+
+        let closers = self.math_closers.get_or_insert_with(|| MathClosers::new(&self.source));
+        let end = opens
+            .then(|| closers.next(length == 2, start))
+            .flatten()
+            .filter(|end| *end > start);
+
+        let Some(end) = end else {
+            return self.literal(DOLLAR, length);
+        };
+
+        self.push(Inline::Math {
+            display: length == 2,
+            text: self.source[start..end].iter().collect(),
+        });
+        self.position = end + length;
+    }
+
+    fn raw_html(&mut self) {
+        let Some(end) = raw_html::inline(&self.source, self.position, &mut self.unterminated_html) else {
+            return self.literal(LESS_THAN, 1);
+        };
+
+        let html = self.source[self.position..end].iter().collect::<String>();
+        self.push(Inline::Html(html));
+        self.position = end;
+    }
+
+    /// `[[Target#Section|Label]]` wiki link, preceding a footnote reference or any other bracket
+    ///
+    /// The text between the brackets spans a single line and contains no other bracket.
+    fn wiki_link(&mut self) {
+        let start = self.position + 2;
+        let link = self.source[start..].iter()
+            .take(self.options.limits.max_link_label_length + 1)
+            .position(|character| matches!(character, &OPENING_BRACKET | &CLOSING_BRACKET | &unicode::LINE_FEED))
+            .filter(|length| self.source[start + length..].starts_with(&[CLOSING_BRACKET, CLOSING_BRACKET]))
+            .and_then(|length| {
+                wiki_link::parse(&self.source[start..start + length].iter().collect::<String>())
+                    .map(|link| (link, start + length + 2))
+            });
+
+        let Some((link, end)) = link else {
+            return self.literal(OPENING_BRACKET, 1);
+        };
+
+        self.push(Inline::WikiLink(link));
+        self.position = end;
+    }
+
+    /// `[^label]` referencing one of the footnote definitions of the document
+    fn footnote_reference(&mut self) {
+        let start = self.position + 2;
+        let label = (self.source.get(self.position + 1) == Some(&CARET))
+            .then(|| {
+                self.source[start..].iter()
+                    .take(self.options.limits.max_link_label_length + 1)
+                    .position(|character| matches!(character, &CLOSING_BRACKET | &OPENING_BRACKET) || character.is_whitespace())
+                    .filter(|length| *length > 0 && self.source[start + length] == CLOSING_BRACKET)
+            })
+            .flatten()
+            .map(|length| self.source[start..start + length].iter().collect::<String>())
+            .filter(|label| self.footnote_labels.contains(&footnote::normalize(label)));
+
+        let Some(label) = label else {
+            return self.literal(OPENING_BRACKET, 1);
+        };
+
+        let end = start + label.chars().count() + 1;
+        self.push(Inline::FootnoteReference {
+            label,
+            index: 0,
+            reference: 0,
+        });
+        self.position = end;
+    }
+
+    /// `:name:` emoji shortcode of a known name, otherwise a text directive if enabled
+    fn emoji(&mut self) {
+        let Some((name, emoji, end)) = emoji::shortcode(&self.source, self.position) else {
+            return match self.options.constructs.directive {
+                true => self.directive(),
+                false => self.literal(COLON, 1),
+            };
+        };
+
+        self.push(Inline::Emoji {
+            name,
+            emoji: emoji.to_string(),
+        });
+        self.position = end;
+    }
+
+    /// `:name[label]{attributes}` text directive, not following a letter, digit or colon
+    fn directive(&mut self) {
+        let directive = match self.previous() {
+            Some(previous) if previous.is_alphanumeric() || previous == COLON => None,
+            _ => directive::parse(DirectiveKind::Text, &self.source, self.position + 1, self.options.limits.max_link_label_length),
+        };
+
+        let Some((directive, end)) = directive else {
+            return self.literal(COLON, 1);
+        };
+
+        self.push(Inline::Directive(directive));
+        self.position = end;
+    }
+
+    /// Run of emphasis delimiters, or of the delimiters of an extension if of a length it allows
+    ///
+    /// A single tilde delimits subscript or strikethrough, a double tilde strikethrough. A caret
+    /// delimits superscript alone, equals signs and plus signs delimit highlighted and inserted
+    /// text in pairs.
+    fn delimiter_run(&mut self, character: char) {
+        let length = self.run_length(character);
+        let constructs = &self.options.constructs;
+
+        let allowed = match (character, length) {
+            (TILDE, 1) => constructs.subscript || (constructs.gfm_strikethrough && self.options.gfm_strikethrough_single_tilde),
+            (TILDE, 2) => constructs.gfm_strikethrough,
+            (CARET, 1) | (EQUALS, 2) | (PLUS, 2) => true,
+            (ASTERISK | UNDERSCORE, _) => true,
+            _ => false,
+        };
+
+        if !allowed || self.delimiters.len() >= self.options.limits.max_delimiter_stack {
+            return self.literal(character, length);
+        }
+
+        let before = self.previous();
+        let after = self.source.get(self.position + length).copied();
+
+        self.flush();
+        let node = self.nodes.push(Node::Delimiter { character, count: length });
+        let run = DelimiterRun::new(node, character, length, before, after);
+
+        if run.is_active() {
+            self.delimiters.push(run);
+        }
+        self.position += length;
+    }
+
+    /// Straight quote, curled once the delimiter stack knows whether it opens or closes a quotation
+    fn quote(&mut self, character: char) {
+        let before = self.previous();
+        let after = self.source.get(self.position + 1).copied();
+
+        self.flush();
+        let node = self.nodes.push(Node::Quote(smart::unmatched_quote(character)));
+        let run = DelimiterRun::new(node, character, 1, before, after);
+
+        if run.is_active() && self.delimiters.len() < self.options.limits.max_delimiter_stack {
+            self.delimiters.push(run);
+        }
+        self.position += 1;
+    }
+
+    /// Run of two or more hyphens replaced by en and em dashes
+    fn dashes(&mut self) {
+        match self.run_length(HYPHEN) {
+            1 => self.literal(HYPHEN, 1),
+            length => {
+                self.text.push_str(&smart::dashes(length));
+                self.position += length;
+            }
+        }
+    }
+
+    /// Three periods replaced by an ellipsis
+    fn ellipsis(&mut self) {
+        match self.source[self.position..].starts_with(&[PERIOD; ELLIPSIS_LENGTH]) {
+            true => {
+                self.text.push_str(ELLIPSIS);
+                self.position += ELLIPSIS_LENGTH;
+            }
+            false => self.literal(PERIOD, 1),
+        }
+    }
+
+    fn url_autolink(&mut self, character: char) {
+        let link = autolink::can_start_after(self.previous())
+            .then(|| autolink::url(&self.source, self.position))
+            .flatten();
+
+        let Some((end, destination)) = link else {
+            return self.literal(character, 1);
+        };
+
+        let text = self.source[self.position..end].iter().collect::<String>();
+        self.push(Inline::Link {
+            destination,
+            title: None,
+            content: vec![Inline::Text(text)],
+        });
+        self.position = end;
+    }
+
+    fn email_autolink(&mut self) {
+        let Some((local_length, end, address)) = autolink::email(&self.text, &self.source, self.position) else {
+            return self.literal(AT, 1);
+        };
+
+        let local_start = self.text.char_indices()
+            .rev()
+            .nth(local_length - 1)
+            .map_or(0, |(index, _)| index);
+        self.text.truncate(local_start);
+
+        self.push(Inline::Link {
+            destination: format!("mailto:{address}"),
+            title: None,
+            content: vec![Inline::Text(address)],
+        });
+        self.position = end;
+    }
+}
+
+/// # Code span closers
+/// Start positions of every backtick run in the source, grouped by run length
+///
+/// Searching for the closer of a code span only moves forward, unmatched openers therefore do not
+/// rescan the source.
+struct CodeSpanClosers {
+    runs: HashMap<usize, Vec<usize>>,
+    cursors: HashMap<usize, usize>,
+}
+
+impl CodeSpanClosers {
+    fn new(source: &[char]) -> Self {
+        let mut runs: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut position = 0;
+
+        while position < source.len() {
+            let length = source[position..].iter()
+                .take_while(|character| **character == BACKTICK)
+                .count();
+
+            if length > 0 {
+                runs.entry(length).or_default().push(position);
+            }
+            position += length.max(1);
+        }
+
+        Self {
+            runs,
+            cursors: HashMap::new(),
+        }
+    }
+
+    /// Start of the first run of `length` backticks at or after `start`
+    fn next(&mut self, length: usize, start: usize) -> Option<usize> {
+        let runs = self.runs.get(&length)?;
+        let cursor = self.cursors.entry(length).or_default();
+
+        while runs.get(*cursor).is_some_and(|position| *position < start) {
+            *cursor += 1;
+        }
+
+        runs.get(*cursor).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::Parser;
+
+    #[test]
+    /// Strikethrough and autolinks (extension): disabled in strict CommonMark
+    fn test_inline_extensions_disabled() {
+        let parser = Parser::from_reader("# ~~Hi~~ www.commonmark.org\n".as_bytes());
+        assert_eq!("<h1>~~Hi~~ www.commonmark.org</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::mem;
+
+use crate::parser::document::block::Block;
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::visitor::VisitorMut;
+use crate::parser::document::Document;
+
+/// # Abbreviations
+/// Abbreviation definitions taken out of the document, keyed by their label
+#[derive(Default)]
+pub struct Abbreviations {
+    titles: HashMap<String, String>,
+    /// Distinct label lengths in bytes by first character of the label, longest first
+    lengths: HashMap<char, Vec<usize>>,
+    limit: usize,
+}
+
+impl Abbreviations {
+    /// Removes every abbreviation definition from the document
+    ///
+    /// The first definition of a label wins, later ones and definitions beyond `limit` are dropped.
+    pub fn extract(document: &mut Document, limit: usize) -> Self {
+        let mut abbreviations = Self {
+            limit,
+            ..Self::default()
+        };
+
+        document.walk_mut(&mut abbreviations);
+        for lengths in abbreviations.lengths.values_mut() {
+            lengths.sort_unstable_by(|a, b| b.cmp(a));
+            lengths.dedup();
+        }
+        abbreviations
+    }
+
+    /// Wraps every whole-word occurrence of a label within text into an [Inline::Abbreviation]
+    ///
+    /// Code and the content of links are left untouched. At every word boundary the longest
+    /// label ending on a word boundary wins.
+    pub fn apply(&self, content: &mut Vec<Inline>) {
+        if self.titles.is_empty() {
+            return;
+        }
+
+        *content = mem::take(content).into_iter()
+            .flat_map(|inline| match inline {
+                Inline::Text(text) => self.split(&text),
+                Inline::Link { .. } => vec![inline],
+                mut inline => {
+                    if let Some(children) = inline.children_mut() {
+                        self.apply(children);
+                    }
+                    vec![inline]
+                }
+            })
+            .collect();
+    }
+
+    fn split(&self, text: &str) -> Vec<Inline> {
+        let mut inlines = vec![];
+        let mut start = 0;
+
+        for (position, character) in text.char_indices() {
+            if position < start || splits_word(text, position) {
+                continue;
+            }
+
+            let abbreviation = self.lengths.get(&character)
+                .into_iter()
+                .flatten()
+                .map(|length| position + length)
+                .filter(|end| text.is_char_boundary(*end) && !splits_word(text, *end))
+                .find_map(|end| self.titles.get_key_value(&text[position..end]).map(|entry| (entry, end)));
+
+            let Some(((label, title), end)) = abbreviation else {
+                continue;
+            };
+
+            if start < position {
+                inlines.push(Inline::Text(text[start..position].to_string()));
+            }
+            inlines.push(Inline::Abbreviation {
+                text: label.clone(),
+                title: title.clone(),
+            });
+            start = end;
+        }
+
+        if start < text.len() {
+            inlines.push(Inline::Text(text[start..].to_string()));
+        }
+
+        inlines
+    }
+}
+
+impl VisitorMut for Abbreviations {
+    fn fold_leaf(&mut self, leaf: Leaf) -> Vec<Block> {
+        let Leaf::AbbreviationDefinition { label, title } = leaf else {
+            return vec![leaf.into()];
+        };
+
+        let first = label.chars().next().unwrap_or_default();
+        if !self.titles.contains_key(&label) && self.titles.len() < self.limit {
+            self.lengths.entry(first).or_default().push(label.len());
+            self.titles.insert(label, title);
+        }
+
+        vec![]
+    }
+}
+
+/// Whether byte `position` lies between two word characters, an abbreviation only starts or ends
+/// there on a side where its label has no word character, such as the end of `C++`
+fn splits_word(text: &str, position: usize) -> bool {
+    let before = text[..position].chars().next_back().is_some_and(is_word);
+    let after = text[position..].chars().next().is_some_and(is_word);
+
+    before && after
+}
+
+fn is_word(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::inline::Inline;
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    #[test]
+    /// Abbreviations (extension)
+    fn test_abbreviation() {
+        let parser = Parser::from_reader_with_options(
+            "# The HTML spec, not HTMLX or HTML_x\n\n*[HTML]: HyperText <Markup> Language\n\n## *HTML* by the W3C\n\n*[W3C]: World Wide Web Consortium\n".as_bytes(),
+            ParseOptions::gfm().with_constructs(|constructs| constructs.abbreviation = true),
+        );
+        let document = parser.parse().unwrap();
+
+        match document.blocks() {
+            [Block::Leaf(Leaf::AtxHeading { content, .. }), Block::Leaf(Leaf::AtxHeading { .. })] => assert_eq!(
+                &Inline::Abbreviation {
+                    text: "HTML".to_string(),
+                    title: "HyperText <Markup> Language".to_string(),
+                },
+                &content[1]
+            ),
+            blocks => panic!("unexpected blocks {blocks:?}"),
+        }
+
+        assert_eq!(
+            "<h1>The <abbr title=\"HyperText &lt;Markup&gt; Language\">HTML</abbr> spec, not HTMLX or HTML_x</h1>\n\
+            <h2><em><abbr title=\"HyperText &lt;Markup&gt; Language\">HTML</abbr></em> by the <abbr title=\"World Wide Web Consortium\">W3C</abbr></h2>\n",
+            document.to_html(&RenderOptions::default())
+        );
+    }
+
+    #[test]
+    /// Abbreviations (extension): longest match and first definition
+    fn test_abbreviation_longest_and_first_definition() {
+        let parser = Parser::from_reader_with_options(
+            "# W3C HTML and HTML\n\n*[HTML]: First\n*[HTML]: Second\n*[W3C HTML]: Both\n".as_bytes(),
+            ParseOptions::gfm().with_constructs(|constructs| constructs.abbreviation = true),
+        );
+        assert_eq!(
+            "<h1><abbr title=\"Both\">W3C HTML</abbr> and <abbr title=\"First\">HTML</abbr></h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Abbreviations (extension): labels starting or ending with a non-word character
+    fn test_abbreviation_non_word_label() {
+        let parser = Parser::from_reader_with_options(
+            "# C++, C++11 and AC++\n\n*[C++]: A programming language\n".as_bytes(),
+            ParseOptions::gfm().with_constructs(|constructs| constructs.abbreviation = true),
+        );
+        assert_eq!(
+            "<h1><abbr title=\"A programming language\">C++</abbr>, <abbr title=\"A programming language\">C++</abbr>11 and AC++</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Abbreviations (extension): code and links are skipped
+    fn test_abbreviation_skips_code_and_links() {
+        let parser = Parser::from_reader_with_options(
+            "# `HTML` www.HTML.com [[HTML]]\n\n*[HTML]: HyperText Markup Language\n*[]: Empty\n".as_bytes(),
+            ParseOptions::gfm().with_constructs(|constructs| {
+                constructs.abbreviation = true;
+                constructs.wiki_link = true;
+            }),
+        );
+        assert_eq!(
+            "<h1><code>HTML</code> <a href=\"http://www.HTML.com\">www.HTML.com</a> <a href=\"HTML\" class=\"wikilink\">HTML</a></h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+
+        let parser = Parser::from_reader_with_options("# HTML\n\n*[HTML]: HyperText Markup Language\n\n# After\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>HTML</h1>\n<h1>After</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
@@ -0,0 +1,179 @@
+/// Characters which may directly precede a literal autolink, besides whitespace
+const BOUNDARIES: [char; 4] = ['*', '_', '~', '('];
+/// Characters excluded from the end of a literal autolink
+const TRAILING_PUNCTUATION: [char; 10] = ['?', '!', '.', ',', ':', '*', '_', '~', '\'', '"'];
+
+/// Whether a literal autolink may start after the given character, [None] standing for the start
+/// of the line
+pub fn can_start_after(previous: Option<char>) -> bool {
+    previous.map_or(true, |character| character.is_whitespace() || BOUNDARIES.contains(&character))
+}
+
+/// # Url autolink
+/// A `www.`, `http://` or `https://` prefix followed by a valid domain and an optional path
+///
+/// Returns the end of the link in `source` and its destination.
+pub fn url(source: &[char], start: usize) -> Option<(usize, String)> {
+    let rest = &source[start..];
+
+    let (domain_start, prefix, require_period) = if starts_with(rest, "www.", false) {
+        (start, "http://", true)
+    } else if starts_with(rest, "http://", true) {
+        (start + 7, "", false)
+    } else if starts_with(rest, "https://", true) {
+        (start + 8, "", false)
+    } else {
+        return None;
+    };
+
+    let domain_end = domain(source, domain_start, require_period)?;
+    let end = source[domain_end..].iter()
+        .position(|character| character.is_whitespace() || *character == '<')
+        .map_or(source.len(), |length| domain_end + length);
+    let end = start + trim_trailing(&source[start..end]);
+
+    if end <= domain_start {
+        return None;
+    }
+
+    Some((end, format!("{prefix}{}", source[start..end].iter().collect::<String>())))
+}
+
+/// # Email autolink
+/// A local part followed by `@` and a domain containing at least one period
+///
+/// `local` is the already scanned text preceding the `@` at `at`. Returns the length of the local
+/// part, the end of the link in `source` and the address.
+pub fn email(local: &str, source: &[char], at: usize) -> Option<(usize, usize, String)> {
+    let local = local.chars()
+        .rev()
+        .take_while(|character| character.is_ascii_alphanumeric() || ".-_+".contains(*character))
+        .collect::<Vec<_>>();
+
+    if local.is_empty() {
+        return None;
+    }
+
+    let mut end = at + 1;
+    let mut periods = 0;
+
+    while let Some(&character) = source.get(end) {
+        if character.is_ascii_alphanumeric() || character == '-' || character == '_' {
+            end += 1;
+        } else if character == '.' && source.get(end + 1).is_some_and(char::is_ascii_alphanumeric) {
+            periods += 1;
+            end += 1;
+        } else {
+            break;
+        }
+    }
+
+    if periods == 0 || matches!(source[end - 1], '-' | '_') {
+        return None;
+    }
+
+    let address = local.iter().rev()
+        .chain(&source[at..end])
+        .collect::<String>();
+
+    Some((local.len(), end, address))
+}
+
+fn starts_with(source: &[char], prefix: &str, ignore_case: bool) -> bool {
+    source.len() >= prefix.len() && source.iter()
+        .zip(prefix.chars())
+        .all(|(character, expected)| match ignore_case {
+            true => character.eq_ignore_ascii_case(&expected),
+            false => *character == expected,
+        })
+}
+
+/// Scans period separated segments of alphanumeric characters, hyphens and underscores
+///
+/// Underscores are not allowed in the last two segments.
+fn domain(source: &[char], start: usize, require_period: bool) -> Option<usize> {
+    let mut end = start;
+    let mut periods = 0;
+    let mut underscore_current = false;
+    let mut underscore_previous = false;
+
+    while let Some(&character) = source.get(end) {
+        match character {
+            '.' => {
+                underscore_previous = underscore_current;
+                underscore_current = false;
+                periods += 1;
+            }
+            '_' => underscore_current = true,
+            character if character.is_alphanumeric() || character == '-' => {}
+            _ => break,
+        }
+        end += 1;
+    }
+
+    let valid = end > start
+        && !underscore_current
+        && !underscore_previous
+        && (periods > 0 || !require_period);
+
+    valid.then_some(end)
+}
+
+/// Length of the link after removing trailing punctuation, unbalanced closing parentheses and
+/// a trailing entity reference
+fn trim_trailing(link: &[char]) -> usize {
+    let mut end = link.len();
+    let opening = link.iter().filter(|character| **character == '(').count();
+    let mut closing = link.iter().filter(|character| **character == ')').count();
+
+    while let Some(&last) = end.checked_sub(1).map(|index| &link[index]) {
+        if TRAILING_PUNCTUATION.contains(&last) {
+            end -= 1;
+        } else if last == ')' {
+            if closing <= opening {
+                break;
+            }
+            closing -= 1;
+            end -= 1;
+        } else if last == ';' {
+            let name = link[..end - 1].iter()
+                .rev()
+                .take_while(|character| character.is_ascii_alphanumeric())
+                .count();
+
+            end -= 1;
+            if name > 0 && end > name && link[end - name - 1] == '&' {
+                end -= name + 1;
+            }
+        } else {
+            break;
+        }
+    }
+
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Autolinks (extension)
+    fn test_autolink_literal() {
+        let parser = Parser::from_reader_with_options("# Visit www.commonmark.org/help, (https://example.com/a_(b)) or foo@bar.baz.\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!(
+            "<h1>Visit <a href=\"http://www.commonmark.org/help\">www.commonmark.org/help</a>, \
+            (<a href=\"https://example.com/a_(b)\">https://example.com/a_(b)</a>) \
+            or <a href=\"mailto:foo@bar.baz\">foo@bar.baz</a>.</h1>\n",
+            parser.parse_to_string().unwrap_or("".into()),
+        );
+    }
+
+    #[test]
+    /// Autolinks (extension): invalid domains and email addresses
+    fn test_autolink_literal_invalid() {
+        let parser = Parser::from_reader_with_options("# www.a_b.c_d foo@bar.baz_ xwww.example.com\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>www.a_b.c_d foo@bar.baz_ xwww.example.com</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+
+use crate::parser::document::inline::Inline;
+use crate::parser::inline::nodes::Nodes;
+use crate::parser::inline::smart::{self, DOUBLE_QUOTE, SINGLE_QUOTE};
+use crate::unicode;
+
+pub const ASTERISK: char = '*';
+pub const UNDERSCORE: char = '_';
+pub const TILDE: char = '~';
+pub const CARET: char = '^';
+pub const EQUALS: char = '=';
+pub const PLUS: char = '+';
+
+/// # Delimiter run
+/// Sequence of [ASTERISK], [UNDERSCORE], [TILDE], [CARET], [EQUALS] or [PLUS] characters that
+/// may open or close emphasis or one of its extensions, or a single straight quote that may open
+/// or close a quotation when punctuation is smart
+pub struct DelimiterRun {
+    node: usize,
+    character: char,
+    length: usize,
+    count: usize,
+    can_open: bool,
+    can_close: bool,
+    previous: Option<usize>,
+    next: Option<usize>,
+}
+
+impl DelimiterRun {
+    /// Classifies a run by the characters surrounding it, [None] standing for a line boundary
+    pub fn new(node: usize, character: char, length: usize, before: Option<char>, after: Option<char>) -> Self {
+        let whitespace_before = before.map_or(true, char::is_whitespace);
+        let whitespace_after = after.map_or(true, char::is_whitespace);
+        let punctuation_before = before.is_some_and(unicode::is_punctuation);
+        let punctuation_after = after.is_some_and(unicode::is_punctuation);
+
+        let left_flanking = !whitespace_after && (!punctuation_after || whitespace_before || punctuation_before);
+        let right_flanking = !whitespace_before && (!punctuation_before || whitespace_after || punctuation_after);
+
+        let (can_open, can_close) = match character {
+            UNDERSCORE => (
+                left_flanking && (!right_flanking || punctuation_before),
+                right_flanking && (!left_flanking || punctuation_after),
+            ),
+            SINGLE_QUOTE | DOUBLE_QUOTE => (
+                left_flanking && !right_flanking && !matches!(before, Some(']' | ')')),
+                right_flanking,
+            ),
+            _ => (left_flanking, right_flanking),
+        };
+
+        Self {
+            node,
+            character,
+            length,
+            count: length,
+            can_open,
+            can_close,
+            previous: None,
+            next: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.can_open || self.can_close
+    }
+
+    fn matches(&self, closer: &DelimiterRun) -> bool {
+        if self.character != closer.character || !self.can_open {
+            return false;
+        }
+
+        match self.character {
+            TILDE | CARET | EQUALS | PLUS => self.count == closer.count,
+            SINGLE_QUOTE | DOUBLE_QUOTE => true,
+            _ => {
+                let ambiguous = self.can_close || closer.can_open;
+                let multiple_of_three = (self.length + closer.length) % 3 == 0;
+
+                !(ambiguous && multiple_of_three && !(self.length % 3 == 0 && closer.length % 3 == 0))
+            }
+        }
+    }
+}
+
+/// # Delimiter stack
+/// Delimiter runs of a block in source order, matched into emphasis once the block is scanned
+pub struct DelimiterStack {
+    runs: Vec<DelimiterRun>,
+    /// Whether a single [TILDE] delimits subscript rather than strikethrough
+    subscript: bool,
+    first: Option<usize>,
+    last: Option<usize>,
+    len: usize,
+}
+
+impl DelimiterStack {
+    pub fn new(subscript: bool) -> Self {
+        Self {
+            runs: Vec::new(),
+            subscript,
+            first: None,
+            last: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn push(&mut self, mut run: DelimiterRun) {
+        let index = self.runs.len();
+        run.previous = self.last;
+
+        match self.last {
+            Some(last) => self.runs[last].next = Some(index),
+            None => self.first = Some(index),
+        }
+        self.last = Some(index);
+        self.len += 1;
+
+        self.runs.push(run);
+    }
+
+    fn remove(&mut self, index: usize) {
+        let (previous, next) = (self.runs[index].previous, self.runs[index].next);
+
+        match previous {
+            Some(previous) => self.runs[previous].next = next,
+            None => self.first = next,
+        }
+
+        match next {
+            Some(next) => self.runs[next].previous = previous,
+            None => self.last = previous,
+        }
+        self.len -= 1;
+    }
+
+    /// Matches closers with the nearest preceding compatible opener, wrapping the nodes between
+    /// them
+    ///
+    /// A quote closer is curled as closing quote, its opener as opening quote. Matched quotes stay
+    /// on the stack and wrap nothing, as in commonmark.js. The search for an opener is bounded per
+    /// kind of closer by the position below which no opener was found before, so the matching
+    /// stays linear in the number of runs.
+    pub fn process(mut self, nodes: &mut Nodes) {
+        let mut openers_bottom: HashMap<(char, bool, usize), usize> = HashMap::new();
+        let mut current = self.first;
+
+        while let Some(closer) = current {
+            if !self.runs[closer].can_close {
+                current = self.runs[closer].next;
+                continue;
+            }
+
+            let key = (self.runs[closer].character, self.runs[closer].can_open, self.runs[closer].length % 3);
+            let bottom = openers_bottom.get(&key).copied();
+            let mut candidate = self.runs[closer].previous;
+
+            let opener = loop {
+                match candidate {
+                    Some(index) if bottom.map_or(true, |bottom| index > bottom) => {
+                        if self.runs[index].matches(&self.runs[closer]) {
+                            break Some(index);
+                        }
+                        candidate = self.runs[index].previous;
+                    }
+                    _ => break None,
+                }
+            };
+
+            if let Some((open, close)) = smart::quotes(self.runs[closer].character) {
+                nodes.set_quote(self.runs[closer].node, close);
+
+                if let Some(opener) = opener {
+                    nodes.set_quote(self.runs[opener].node, open);
+                    current = self.runs[closer].next;
+                    continue;
+                }
+            }
+
+            let Some(opener) = opener else {
+                if let Some(previous) = self.runs[closer].previous {
+                    openers_bottom.insert(key, previous);
+                }
+
+                current = self.runs[closer].next;
+                if !self.runs[closer].can_open {
+                    self.remove(closer);
+                }
+                continue;
+            };
+
+            let used = match self.runs[closer].character {
+                TILDE | CARET | EQUALS | PLUS => self.runs[closer].count,
+                _ if self.runs[opener].count >= 2 && self.runs[closer].count >= 2 => 2,
+                _ => 1,
+            };
+
+            self.runs[opener].count -= used;
+            self.runs[closer].count -= used;
+            nodes.set_count(self.runs[opener].node, self.runs[opener].count);
+            nodes.set_count(self.runs[closer].node, self.runs[closer].count);
+
+            nodes.wrap_between(self.runs[opener].node, self.runs[closer].node, |content| {
+                match (self.runs[closer].character, used) {
+                    (TILDE, 1) if self.subscript => Inline::Subscript(content),
+                    (TILDE, _) => Inline::Strikethrough(content),
+                    (CARET, _) => Inline::Superscript(content),
+                    (EQUALS, _) => Inline::Mark(content),
+                    (PLUS, _) => Inline::Inserted(content),
+                    (_, 2) => Inline::Strong(content),
+                    _ => Inline::Emphasis(content),
+                }
+            });
+
+            let mut between = self.runs[opener].next;
+            while let Some(index) = between.filter(|index| *index != closer) {
+                between = self.runs[index].next;
+                self.remove(index);
+            }
+
+            if self.runs[opener].count == 0 {
+                nodes.remove(self.runs[opener].node);
+                self.remove(opener);
+            }
+
+            if self.runs[closer].count == 0 {
+                current = self.runs[closer].next;
+                nodes.remove(self.runs[closer].node);
+                self.remove(closer);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Strikethrough (extension)
+    fn test_strikethrough() {
+        let parser = Parser::from_reader_with_options("# ~~Hi~~ Hello, ~there~ world!\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1><del>Hi</del> Hello, <del>there</del> world!</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Strikethrough (extension): runs of more than two tildes
+    fn test_strikethrough_long_run() {
+        let parser = Parser::from_reader_with_options("# ~~~foo~~~ \\~~bar~~\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>~~~foo~~~ ~~bar~~</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Strikethrough (extension): single tilde disabled
+    fn test_strikethrough_single_tilde_disabled() {
+        let options = ParseOptions {
+            gfm_strikethrough_single_tilde: false,
+            ..ParseOptions::gfm()
+        };
+        let parser = Parser::from_reader_with_options("# ~~Hi~~ Hello, ~there~ world!\n".as_bytes(), options);
+        assert_eq!("<h1><del>Hi</del> Hello, ~there~ world!</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Strikethrough (extension): tilde runs at the start of a block are no code fence
+    fn test_strikethrough_not_fence() {
+        let parser = Parser::from_reader_with_options("~~foo~~\n===\n\n# bar\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1><del>foo</del></h1>\n<h1>bar</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Superscript, subscript, mark and inserted text (extension)
+    fn test_superscript_subscript_mark_inserted() {
+        let options = ParseOptions::gfm().with_constructs(|constructs| {
+            constructs.superscript = true;
+            constructs.subscript = true;
+            constructs.mark = true;
+            constructs.inserted = true;
+        });
+        let parser = Parser::from_reader_with_options("# x^2^ H~2~O ~~gone~~ ==hi *there*== ++new++ ^*a*^\n".as_bytes(), options);
+        assert_eq!(
+            "<h1>x<sup>2</sup> H<sub>2</sub>O <del>gone</del> <mark>hi <em>there</em></mark> <ins>new</ins> <sup><em>a</em></sup></h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Superscript, subscript, mark and inserted text (extension): flanking runs
+    fn test_superscript_subscript_mark_inserted_flanking() {
+        let options = ParseOptions::gfm().with_constructs(|constructs| {
+            constructs.superscript = true;
+            constructs.subscript = true;
+            constructs.mark = true;
+            constructs.inserted = true;
+        });
+        let parser = Parser::from_reader_with_options("# a ^ b ^ c ^^d^^ ===x=== ++ y++ 1+1 a=b ~z\n".as_bytes(), options);
+        assert_eq!(
+            "<h1>a ^ b ^ c ^^d^^ ===x=== ++ y++ 1+1 a=b ~z</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Superscript, subscript, mark and inserted text (extension): separate toggles
+    fn test_superscript_subscript_mark_inserted_toggles() {
+        let options = ParseOptions::gfm().with_constructs(|constructs| constructs.mark = true);
+        let parser = Parser::from_reader_with_options("# ^a^ ~b~ ==c== ++d++\n".as_bytes(), options);
+        assert_eq!("<h1>^a^ <del>b</del> <mark>c</mark> ++d++</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+
+        let options = ParseOptions::commonmark().with_constructs(|constructs| constructs.subscript = true);
+        let parser = Parser::from_reader_with_options("# ~b~ ~~c~~\n".as_bytes(), options);
+        assert_eq!("<h1><sub>b</sub> ~~c~~</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
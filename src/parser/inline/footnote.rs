@@ -0,0 +1,249 @@
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::document::visitor::{self, VisitorMut};
+use crate::parser::document::Document;
+
+/// Labels match case-insensitively
+pub fn normalize(label: &str) -> String {
+    label.to_lowercase()
+}
+
+/// # Footnote definitions
+/// Footnote definitions taken out of the document, keyed by their normalized label
+#[derive(Default)]
+pub struct FootnoteDefinitions {
+    definitions: HashMap<String, Container>,
+    labels: HashMap<String, String>,
+}
+
+impl FootnoteDefinitions {
+    /// Removes every footnote definition from the document
+    ///
+    /// The first definition of a label wins, later ones and definitions beyond `limit` are dropped.
+    pub fn extract(document: &mut Document, limit: usize) -> Self {
+        let mut extraction = Extraction {
+            definitions: Self::default(),
+            limit,
+        };
+
+        document.walk_mut(&mut extraction);
+        extraction.definitions
+    }
+
+    /// Normalized labels of the definitions, the only labels references may use
+    pub fn labels(&self) -> HashSet<String> {
+        self.labels.keys().cloned().collect()
+    }
+
+    /// Folds the content of every definition
+    pub fn fold<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        for container in self.definitions.values_mut() {
+            container.content = visitor::fold_blocks(visitor, mem::take(&mut container.content));
+        }
+    }
+
+    /// Numbers the references in order of first use and appends the referenced definitions in
+    /// the same order as a [ContainerKind::Footnotes] section
+    ///
+    /// References inside definitions are numbered after the references of the document, in
+    /// order of the definitions. Unreferenced definitions are dropped.
+    pub fn resolve(mut self, document: &mut Document) {
+        let mut numbering = Numbering {
+            labels: &self.labels,
+            order: vec![],
+            indices: HashMap::new(),
+            references: HashMap::new(),
+        };
+
+        document.walk_mut(&mut numbering);
+
+        let mut notes = vec![];
+        let mut position = 0;
+
+        while let Some(key) = numbering.order.get(position).cloned() {
+            if let Some(mut container) = self.definitions.remove(&key) {
+                container.content = visitor::fold_blocks(&mut numbering, mem::take(&mut container.content));
+                notes.push((key, container));
+            }
+            position += 1;
+        }
+
+        if notes.is_empty() {
+            return;
+        }
+
+        let notes = notes.into_iter()
+            .map(|(key, mut container)| {
+                if let ContainerKind::FootnoteDefinition { index, references, .. } = &mut container.kind {
+                    *index = numbering.indices.get(&key).copied().unwrap_or_default();
+                    *references = numbering.references.get(&key).copied().unwrap_or_default();
+                }
+
+                container.into()
+            })
+            .collect();
+
+        document.push(Container::new(ContainerKind::Footnotes, notes).into());
+    }
+}
+
+/// # Extraction
+/// Removes footnote definitions from the document
+struct Extraction {
+    definitions: FootnoteDefinitions,
+    limit: usize,
+}
+
+impl VisitorMut for Extraction {
+    fn fold_container(&mut self, mut container: Container) -> Vec<Block> {
+        let ContainerKind::FootnoteDefinition { label, .. } = &container.kind else {
+            container.content = visitor::fold_blocks(self, container.content);
+            return vec![container.into()];
+        };
+
+        let key = normalize(label);
+        let definitions = &mut self.definitions;
+
+        if !definitions.labels.contains_key(&key) && definitions.labels.len() < self.limit {
+            definitions.labels.insert(key.clone(), label.clone());
+            definitions.definitions.insert(key, container);
+        }
+
+        vec![]
+    }
+}
+
+/// # Numbering
+/// Assigns its footnote number and its position among the references to the same footnote to
+/// every footnote reference
+struct Numbering<'a> {
+    labels: &'a HashMap<String, String>,
+    /// Normalized labels in order of first reference
+    order: Vec<String>,
+    indices: HashMap<String, usize>,
+    references: HashMap<String, usize>,
+}
+
+impl Numbering<'_> {
+    fn number(&mut self, content: &mut [Inline]) {
+        for inline in content {
+            if let Inline::FootnoteReference { label, index, reference } = inline {
+                let key = normalize(label);
+                let Some(definition) = self.labels.get(&key) else {
+                    continue;
+                };
+
+                let count = self.references.entry(key.clone()).or_default();
+                *count += 1;
+                *reference = *count;
+
+                *index = match self.indices.get(&key) {
+                    Some(index) => *index,
+                    None => {
+                        self.order.push(key.clone());
+                        self.indices.insert(key, self.order.len());
+                        self.order.len()
+                    }
+                };
+                *label = definition.clone();
+            } else if let Some(children) = inline.children_mut() {
+                self.number(children);
+            }
+        }
+    }
+}
+
+impl VisitorMut for Numbering<'_> {
+    fn fold_leaf(&mut self, mut leaf: Leaf) -> Vec<Block> {
+        for content in leaf.inlines_mut() {
+            self.number(content);
+        }
+
+        vec![leaf.into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Footnotes (extension)
+    fn test_footnotes() {
+        let parser = Parser::from_reader_with_options("# Title[^note]\n\n[^note]: A *note*.\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!(
+            "<h1>Title<sup class=\"footnote-ref\"><a href=\"#fn-note\" id=\"fnref-note\" data-footnote-ref>1</a></sup></h1>\n\
+            <section class=\"footnotes\" data-footnotes>\n<ol>\n<li id=\"fn-note\">\n\
+            <p>A <em>note</em>. <a href=\"#fnref-note\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1\" aria-label=\"Back to reference 1\">↩</a></p>\n\
+            </li>\n</ol>\n</section>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Footnotes (extension): repeated references share a number and get a back reference each
+    fn test_footnotes_multiple_references() {
+        let parser = Parser::from_reader_with_options("# a[^2] b[^1] c[^2]\n\n[^1]: One\n\n[^2]: Two\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!(
+            "<h1>a<sup class=\"footnote-ref\"><a href=\"#fn-2\" id=\"fnref-2\" data-footnote-ref>1</a></sup> \
+            b<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\" data-footnote-ref>2</a></sup> \
+            c<sup class=\"footnote-ref\"><a href=\"#fn-2\" id=\"fnref-2-2\" data-footnote-ref>1</a></sup></h1>\n\
+            <section class=\"footnotes\" data-footnotes>\n<ol>\n<li id=\"fn-2\">\n\
+            <p>Two <a href=\"#fnref-2\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1\" aria-label=\"Back to reference 1\">↩</a> \
+            <a href=\"#fnref-2-2\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1-2\" aria-label=\"Back to reference 1-2\">↩<sup class=\"footnote-ref\">2</sup></a></p>\n\
+            </li>\n<li id=\"fn-1\">\n\
+            <p>One <a href=\"#fnref-1\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"2\" aria-label=\"Back to reference 2\">↩</a></p>\n\
+            </li>\n</ol>\n</section>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Footnotes (extension): indented continuation paragraphs
+    fn test_footnotes_multiple_paragraphs() {
+        let parser = Parser::from_reader_with_options("# a[^1]\n\n[^1]: First\n    line.\n\n    Second.\n# b\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!(
+            "<h1>a<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\" data-footnote-ref>1</a></sup></h1>\n\
+            <h1>b</h1>\n\
+            <section class=\"footnotes\" data-footnotes>\n<ol>\n<li id=\"fn-1\">\n\
+            <p>First\nline.</p>\n\
+            <p>Second. <a href=\"#fnref-1\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1\" aria-label=\"Back to reference 1\">↩</a></p>\n\
+            </li>\n</ol>\n</section>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Footnotes (extension): lazy continuation lines, ended by a line starting another block
+    fn test_footnotes_lazy_continuation() {
+        let parser = Parser::from_reader_with_options("# a[^1]\n\n[^1]: First\nlazy\n# b\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!(
+            "<h1>a<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\" data-footnote-ref>1</a></sup></h1>\n\
+            <h1>b</h1>\n\
+            <section class=\"footnotes\" data-footnotes>\n<ol>\n<li id=\"fn-1\">\n\
+            <p>First\nlazy <a href=\"#fnref-1\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1\" aria-label=\"Back to reference 1\">↩</a></p>\n\
+            </li>\n</ol>\n</section>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Footnotes (extension): unreferenced definitions and undefined references are dropped
+    fn test_footnotes_unreferenced() {
+        let parser = Parser::from_reader_with_options("# a[^missing]\n\n[^unused]: Gone.\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>a[^missing]</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Footnotes (extension): disabled in strict CommonMark
+    fn test_footnotes_disabled() {
+        let parser = Parser::from_reader_with_options("# a[^1]\n".as_bytes(), ParseOptions::default());
+        assert_eq!("<h1>a[^1]</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
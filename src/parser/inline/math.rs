@@ -0,0 +1,136 @@
+use crate::unicode;
+
+pub const DOLLAR: char = '$';
+
+/// # Math closers
+/// Start positions of the dollar runs able to close inline math, a single dollar, or display
+/// math, two dollars
+///
+/// Dollars escaped by a backslash never close math. A single dollar only closes inline math when
+/// it follows a non-whitespace character and is not followed by a digit, so `$5 and $10` stays
+/// text. Searching for a closer only moves forward, unmatched openers therefore do not rescan
+/// the source.
+pub struct MathClosers {
+    inline: Vec<usize>,
+    display: Vec<usize>,
+    inline_cursor: usize,
+    display_cursor: usize,
+}
+
+impl MathClosers {
+    pub fn new(source: &[char]) -> Self {
+        let mut inline = vec![];
+        let mut display = vec![];
+        let mut position = 0;
+
+        while let Some(&character) = source.get(position) {
+            match character {
+                unicode::BACKSLASH => position += 2,
+                DOLLAR => {
+                    let length = source[position..].iter()
+                        .take_while(|character| **character == DOLLAR)
+                        .count();
+
+                    match length {
+                        1 if can_close_inline(source, position) => inline.push(position),
+                        2 => display.push(position),
+                        _ => {}
+                    }
+                    position += length;
+                }
+                _ => position += 1,
+            }
+        }
+
+        Self {
+            inline,
+            display,
+            inline_cursor: 0,
+            display_cursor: 0,
+        }
+    }
+
+    /// Start of the first closer of inline or display math at or after `start`
+    pub fn next(&mut self, display: bool, start: usize) -> Option<usize> {
+        let (closers, cursor) = match display {
+            true => (&self.display, &mut self.display_cursor),
+            false => (&self.inline, &mut self.inline_cursor),
+        };
+
+        while closers.get(*cursor).is_some_and(|position| *position < start) {
+            *cursor += 1;
+        }
+
+        closers.get(*cursor).copied()
+    }
+}
+
+/// A single dollar opens inline math when it is followed by a non-whitespace character
+pub fn can_open_inline(source: &[char], position: usize) -> bool {
+    source.get(position + 1).is_some_and(|next| !next.is_whitespace())
+}
+
+fn can_close_inline(source: &[char], position: usize) -> bool {
+    let after_content = position.checked_sub(1)
+        .is_some_and(|index| !source[index].is_whitespace());
+    let before_digit = source.get(position + 1).is_some_and(char::is_ascii_digit);
+
+    after_content && !before_digit
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Math (extension)
+    fn test_math_inline() {
+        let parser = Parser::from_reader_with_options("# $a<b$ and $$\\sum_i x_i$$\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.math_text = true));
+        assert_eq!(
+            "<h1><span class=\"math inline\">a&lt;b</span> and <span class=\"math display\">\\sum_i x_i</span></h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Math (extension): display math within a paragraph stays phrasing content
+    fn test_math_inline_display_paragraph() {
+        let parser = Parser::from_reader_with_options("- a\n\n  $$x$$ b\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.math_text = true));
+        assert_eq!(
+            "<ul>\n<li>\n<p>a</p>\n<p><span class=\"math display\">x</span> b</p>\n</li>\n</ul>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Math (extension): dollar amounts are no math
+    fn test_math_inline_currency() {
+        let parser = Parser::from_reader_with_options("# $5 and $10\n# $ x$\n# $x $\n# $x$1\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.math_text = true));
+        assert_eq!("<h1>$5 and $10</h1>\n<h1>$ x$</h1>\n<h1>$x $</h1>\n<h1>$x$1</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Math (extension): escaped dollars and code spans
+    fn test_math_inline_escaped_dollar() {
+        let parser = Parser::from_reader_with_options("# $a\\$b$ `$c$`\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.math_text = true));
+        assert_eq!(
+            "<h1><span class=\"math inline\">a\\$b</span> <code>$c$</code></h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Math (extension): math code blocks
+    fn test_math_fenced() {
+        let parser = Parser::from_reader_with_options("```math\nx^2 < y\n```\n".as_bytes(), ParseOptions::default().with_constructs(|constructs| constructs.math_flow = true));
+        assert_eq!("<div class=\"math display\">x^2 &lt; y</div>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Math (extension): disabled by default
+    fn test_math_disabled() {
+        let parser = Parser::from_reader_with_options("# $x$\n```math\nx\n```\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>$x$</h1>\n<pre><code class=\"language-math\">x\n</code></pre>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
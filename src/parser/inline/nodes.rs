@@ -0,0 +1,150 @@
+use crate::parser::document::inline::Inline;
+
+pub enum Node {
+    Inline(Inline),
+    /// Unmatched remainder of a delimiter run, becomes text unless matched
+    Delimiter {
+        character: char,
+        count: usize,
+    },
+    /// Curly quote replacing a straight quote, decided once the quote is matched
+    Quote(&'static str),
+}
+
+impl Node {
+    fn into_inline(self) -> Option<Inline> {
+        match self {
+            Node::Inline(inline) => Some(inline),
+            Node::Delimiter { count: 0, .. } => None,
+            Node::Delimiter { character, count } => Some(Inline::Text(character.to_string().repeat(count))),
+            Node::Quote(quote) => Some(Inline::Text(quote.to_string())),
+        }
+    }
+}
+
+struct Slot {
+    node: Option<Node>,
+    previous: Option<usize>,
+    next: Option<usize>,
+}
+
+/// # Nodes
+/// Doubly linked list of the inline nodes of a block
+///
+/// Wrapping a range of nodes into a new node moves every node at most once per nesting level,
+/// which keeps matching delimiter runs linear in the number of nodes.
+pub struct Nodes {
+    slots: Vec<Slot>,
+    first: Option<usize>,
+    last: Option<usize>,
+}
+
+impl Nodes {
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            first: None,
+            last: None,
+        }
+    }
+
+    pub fn push(&mut self, node: Node) -> usize {
+        let index = self.slots.len();
+        self.slots.push(Slot {
+            node: Some(node),
+            previous: self.last,
+            next: None,
+        });
+
+        match self.last {
+            Some(last) => self.slots[last].next = Some(index),
+            None => self.first = Some(index),
+        }
+        self.last = Some(index);
+
+        index
+    }
+
+    /// Appends an inline node, merging adjacent text
+    pub fn push_inline(&mut self, inline: Inline) {
+        if let (Inline::Text(text), Some(last)) = (&inline, self.last) {
+            if let Some(Node::Inline(Inline::Text(previous))) = &mut self.slots[last].node {
+                previous.push_str(text);
+                return;
+            }
+        }
+
+        self.push(Node::Inline(inline));
+    }
+
+    pub fn set_count(&mut self, index: usize, value: usize) {
+        if let Some(Node::Delimiter { count, .. }) = &mut self.slots[index].node {
+            *count = value;
+        }
+    }
+
+    pub fn set_quote(&mut self, index: usize, value: &'static str) {
+        if let Some(Node::Quote(quote)) = &mut self.slots[index].node {
+            *quote = value;
+        }
+    }
+
+    /// Replaces the nodes strictly between `from` and `to` by the node produced by `wrap`
+    pub fn wrap_between(&mut self, from: usize, to: usize, wrap: impl FnOnce(Vec<Inline>) -> Inline) {
+        let mut content = Vec::new();
+        let mut current = self.slots[from].next;
+
+        while let Some(index) = current.filter(|index| *index != to) {
+            current = self.slots[index].next;
+            if let Some(inline) = self.slots[index].node.take().and_then(Node::into_inline) {
+                append(&mut content, inline);
+            }
+        }
+
+        let index = self.slots.len();
+        self.slots.push(Slot {
+            node: Some(Node::Inline(wrap(content))),
+            previous: Some(from),
+            next: Some(to),
+        });
+        self.slots[from].next = Some(index);
+        self.slots[to].previous = Some(index);
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        let Slot { previous, next, .. } = self.slots[index];
+        self.slots[index].node = None;
+
+        match previous {
+            Some(previous) => self.slots[previous].next = next,
+            None => self.first = next,
+        }
+
+        match next {
+            Some(next) => self.slots[next].previous = previous,
+            None => self.last = previous,
+        }
+    }
+
+    pub fn into_inlines(mut self) -> Vec<Inline> {
+        let mut inlines = Vec::new();
+        let mut current = self.first;
+
+        while let Some(index) = current {
+            current = self.slots[index].next;
+            if let Some(inline) = self.slots[index].node.take().and_then(Node::into_inline) {
+                append(&mut inlines, inline);
+            }
+        }
+
+        inlines
+    }
+}
+
+fn append(inlines: &mut Vec<Inline>, inline: Inline) {
+    if let (Inline::Text(text), Some(Inline::Text(previous))) = (&inline, inlines.last_mut()) {
+        previous.push_str(text);
+    } else {
+        inlines.push(inline);
+    }
+}
@@ -0,0 +1,108 @@
+pub const SINGLE_QUOTE: char = '\'';
+pub const DOUBLE_QUOTE: char = '"';
+pub const HYPHEN: char = '-';
+pub const PERIOD: char = '.';
+
+const EN_DASH: &str = "\u{2013}";
+const EM_DASH: &str = "\u{2014}";
+pub const ELLIPSIS: &str = "\u{2026}";
+
+/// Number of [PERIOD] forming an [ELLIPSIS]
+pub const ELLIPSIS_LENGTH: usize = 3;
+
+/// Opening and closing curly quotes replacing a straight quote
+pub fn quotes(character: char) -> Option<(&'static str, &'static str)> {
+    match character {
+        SINGLE_QUOTE => Some(("\u{2018}", "\u{2019}")),
+        DOUBLE_QUOTE => Some(("\u{201C}", "\u{201D}")),
+        _ => None,
+    }
+}
+
+/// Curly quote of a straight quote before it is matched, an apostrophe or an opening double quote
+pub fn unmatched_quote(character: char) -> &'static str {
+    match character {
+        SINGLE_QUOTE => "\u{2019}",
+        _ => "\u{201C}",
+    }
+}
+
+/// Dashes replacing a run of `length` hyphens
+///
+/// Runs are split into em dashes only, en dashes only, or em dashes followed by one or two en
+/// dashes, as in commonmark.js.
+pub fn dashes(length: usize) -> String {
+    let (em, en) = if length % 3 == 0 {
+        (length / 3, 0)
+    } else if length % 2 == 0 {
+        (0, length / 2)
+    } else if length % 3 == 2 {
+        ((length - 2) / 3, 1)
+    } else {
+        ((length - 4) / 3, 2)
+    };
+
+    EM_DASH.repeat(em) + &EN_DASH.repeat(en)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Smart punctuation (extension): quotes
+    fn test_smart_quotes() {
+        let parser = Parser::from_reader_with_options(
+            "# \"Hello,\" said the spider. \"'Shelob' is my name.\"\n\
+            # 'He said, \"I want to go.\"'\n\
+            # 'tis the season to be 'jolly' in the 70's\n\
+            # 'We'll use Jane's boat,' Jenna said.\n\
+            # [a]'s b' *\"a\"*\n".as_bytes(),
+            ParseOptions { smart_punctuation: true, ..ParseOptions::gfm() },
+        );
+        assert_eq!(
+            "<h1>“Hello,” said the spider. “‘Shelob’ is my name.”</h1>\n\
+            <h1>‘He said, “I want to go.”’</h1>\n\
+            <h1>’tis the season to be ‘jolly’ in the 70’s</h1>\n\
+            <h1>‘We’ll use Jane’s boat,’ Jenna said.</h1>\n\
+            <h1>[a]’s b’ <em>“a”</em></h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Smart punctuation (extension): dashes and ellipses
+    fn test_smart_dashes_and_ellipses() {
+        let parser = Parser::from_reader_with_options(
+            "# em---em en--en 2--3\n\
+            # one- two-- three--- four---- five----- six------ seven------- thirteen-------------.\n\
+            # Ellipses...and...and....\n".as_bytes(),
+            ParseOptions { smart_punctuation: true, ..ParseOptions::gfm() },
+        );
+        assert_eq!(
+            "<h1>em—em en–en 2–3</h1>\n\
+            <h1>one- two– three— four–– five—– six—— seven—–– thirteen———––.</h1>\n\
+            <h1>Ellipses…and…and….</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Smart punctuation (extension): escapes, code and disabled
+    fn test_smart_punctuation_untouched() {
+        let parser = Parser::from_reader_with_options(
+            "# \\\"Not smart.\\\" 5\\'8\\\" \\-- No ellipses\\.\\.\\. `\"code\" -- ...`\n\n```\n'fenced' -- ...\n```\n\n    'indented' ---\n".as_bytes(),
+            ParseOptions { smart_punctuation: true, ..ParseOptions::gfm() },
+        );
+        assert_eq!(
+            "<h1>&quot;Not smart.&quot; 5'8&quot; -- No ellipses... <code>&quot;code&quot; -- ...</code></h1>\n\
+            <pre><code>'fenced' -- ...\n</code></pre>\n\
+            <pre><code>'indented' ---\n</code></pre>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+
+        let parser = Parser::from_reader_with_options("# \"Hi\" -- ...\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>&quot;Hi&quot; -- ...</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
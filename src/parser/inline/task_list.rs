@@ -0,0 +1,88 @@
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+
+/// Turns a list item starting with a `[ ]`, `[x]` or `[X]` marker followed by whitespace into a
+/// task list item, removing the marker from its first paragraph
+pub fn resolve(container: &mut Container) {
+    if container.kind != ContainerKind::ListItem {
+        return;
+    }
+
+    let Some(Block::Leaf(Leaf::Paragraph { content })) = container.content.first_mut() else {
+        return;
+    };
+
+    let Some(Inline::Text(text)) = content.first_mut() else {
+        return;
+    };
+
+    let checked = match text.get(..3) {
+        Some("[ ]") => false,
+        Some("[x]" | "[X]") => true,
+        _ => return,
+    };
+
+    let Some(rest) = text[3..].strip_prefix([' ', '\t']) else {
+        return;
+    };
+
+    *text = rest.to_string();
+    if text.is_empty() {
+        content.remove(0);
+    }
+
+    container.kind = ContainerKind::TaskListItem { checked };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::builder::Builder;
+    use crate::parser::document::container::ListKind;
+    use crate::parser::document::Document;
+    use crate::parser::inline;
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Task list items (extension)
+    fn test_task_list_items() {
+        let parser = Parser::from_reader_with_options("- [ ] foo\n- [x] bar\n- [y] baz\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!(
+            "<ul>\n\
+            <li><input disabled=\"\" type=\"checkbox\"> foo</li>\n\
+            <li><input checked=\"\" disabled=\"\" type=\"checkbox\"> bar</li>\n\
+            <li>[y] baz</li>\n\
+            </ul>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+
+        let parser = Parser::from_reader_with_options("- [ ] foo\n".as_bytes(), ParseOptions::commonmark());
+        assert_eq!("<ul>\n<li>[ ] foo</li>\n</ul>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Task list items (extension): markers of list items assembled with the builder are resolved
+    /// by the inline pass
+    fn test_task_list_items_builder() {
+        let mut document = Document::builder()
+            .list(ListKind::Bullet, true, [
+                Builder::new().paragraph(["[ ] foo".into()]),
+                Builder::new().paragraph(["[x] bar".into()]),
+                Builder::new().paragraph(["[y] baz".into()]),
+            ])
+            .build()
+            .unwrap();
+        inline::parse_document(&mut document, &ParseOptions::gfm());
+
+        assert_eq!(
+            "<ul>\n\
+            <li><input disabled=\"\" type=\"checkbox\"> foo</li>\n\
+            <li><input checked=\"\" disabled=\"\" type=\"checkbox\"> bar</li>\n\
+            <li>[y] baz</li>\n\
+            </ul>\n",
+            document.to_string(),
+        );
+    }
+}
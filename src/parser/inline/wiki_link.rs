@@ -0,0 +1,103 @@
+use crate::parser::document::wiki_link::WikiLink;
+
+const PIPE: char = '|';
+const HASH: char = '#';
+
+/// Wiki link of the text between `[[` and `]]`
+///
+/// The text up to the first [PIPE] is the target, the rest the label. The target is split at its
+/// first [HASH] into page and section. Links without page and section are not recognized.
+pub fn parse(text: &str) -> Option<WikiLink> {
+    let (destination, label) = match text.split_once(PIPE) {
+        Some((destination, label)) => (destination, Some(label.trim())),
+        None => (text, None),
+    };
+
+    let (target, section) = match destination.split_once(HASH) {
+        Some((target, section)) => (target.trim(), Some(section.trim())),
+        None => (destination.trim(), None),
+    };
+
+    let section = section.filter(|section| !section.is_empty());
+    if target.is_empty() && section.is_none() {
+        return None;
+    }
+
+    Some(WikiLink {
+        target: target.to_string(),
+        section: section.map(str::to_string),
+        label: label.filter(|label| !label.is_empty()).map(str::to_string),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::inline::Inline;
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::document::wiki_link::{WikiLink, WikiLinkResolver};
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    #[test]
+    /// Wiki links (extension)
+    fn test_wiki_link() {
+        let parser = Parser::from_reader_with_options("# [[Home]], [[Guide#Getting Started|the guide]] and [[#Top]]\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.wiki_link = true));
+        let document = parser.parse().unwrap();
+
+        match document.blocks() {
+            [Block::Leaf(Leaf::AtxHeading { content, .. })] => assert_eq!(
+                &Inline::WikiLink(WikiLink {
+                    target: "Guide".to_string(),
+                    section: Some("Getting Started".to_string()),
+                    label: Some("the guide".to_string()),
+                }),
+                &content[2]
+            ),
+            blocks => panic!("unexpected blocks {blocks:?}"),
+        }
+
+        assert_eq!(
+            "<h1><a href=\"Home\" class=\"wikilink\">Home</a>, <a href=\"Guide#getting-started\" class=\"wikilink\">the guide</a> \
+            and <a href=\"#top\" class=\"wikilink\">#Top</a></h1>\n",
+            document.to_html(&RenderOptions::default())
+        );
+    }
+
+    #[test]
+    /// Wiki links (extension): resolver and broken links
+    fn test_wiki_link_resolver() {
+        let parser = Parser::from_reader_with_options("# [[Known Page]] [[Missing|gone]]\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.wiki_link = true));
+        let document = parser.parse().unwrap();
+
+        let options = RenderOptions {
+            wiki_link_resolver: Some(WikiLinkResolver::new(|link: &WikiLink| {
+                (link.target == "Known Page").then(|| format!("/wiki/{}", link.target.replace(' ', "_")))
+            })),
+            wiki_link_broken_class: "new".to_string(),
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(
+            "<h1><a href=\"/wiki/Known_Page\" class=\"wikilink\">Known Page</a> <a class=\"wikilink new\">gone</a></h1>\n",
+            document.to_html(&options)
+        );
+    }
+
+    #[test]
+    /// Wiki links (extension): precedence over brackets and footnotes
+    fn test_wiki_link_precedence() {
+        let parser = Parser::from_reader_with_options("# [[[Page]]] [[a]b]] [[ | x]] [[open [^1]\n\n[^1]: Note\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.wiki_link = true));
+        assert_eq!(
+            "<h1>[<a href=\"Page\" class=\"wikilink\">Page</a>] [[a]b]] [[ | x]] [[open \
+            <sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\" data-footnote-ref>1</a></sup></h1>\n\
+            <section class=\"footnotes\" data-footnotes>\n<ol>\n<li id=\"fn-1\">\n\
+            <p>Note <a href=\"#fnref-1\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1\" aria-label=\"Back to reference 1\">↩</a></p>\n\
+            </li>\n</ol>\n</section>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+
+        let parser = Parser::from_reader_with_options("# [[Page]]\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>[[Page]]</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
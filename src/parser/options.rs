@@ -0,0 +1,345 @@
+use crate::parser::document::code_block::CodeBlockHandlers;
+use crate::parser::document::directive::{DirectiveHandlers, UnhandledDirectives};
+use crate::parser::document::highlight::Highlighter;
+use crate::parser::document::wiki_link::WikiLinkResolver;
+
+/// # Constructs
+/// Block and inline constructs recognized by the parser
+///
+/// Disabled block constructs are never considered as a candidate when a new block starts,
+/// disabled inline constructs are kept as text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constructs {
+    pub thematic_break: bool,
+    pub atx_heading: bool,
+    pub setext_heading: bool,
+    pub indented_code_block: bool,
+    pub fenced_code_block: bool,
+    /// Bullet and ordered lists, their items hold paragraphs only
+    pub list: bool,
+    /// HTML blocks
+    pub html_flow: bool,
+    /// Inline raw HTML
+    pub html_text: bool,
+    pub gfm_table: bool,
+    pub gfm_strikethrough: bool,
+    /// `[ ]` and `[x]` markers turning list items into task list items
+    pub gfm_task_list_item: bool,
+    pub gfm_autolink_literal: bool,
+    pub gfm_footnote_definition: bool,
+    pub gfm_footnote_reference: bool,
+    /// YAML or TOML metadata block at the very start of the document, see
+    /// [crate::parser::document::front_matter]
+    pub front_matter: bool,
+    /// `$inline$` and `$$display$$` math
+    pub math_text: bool,
+    /// Fenced code blocks with a `math` info string
+    pub math_flow: bool,
+    /// `[[_TOC_]]` or `[TOC]` placeholder lines, replaced by the table of contents when rendering
+    pub table_of_contents: bool,
+    /// `{#id .class key=value}` attribute blocks trailing headings and fenced code block info
+    /// strings
+    pub attributes: bool,
+    /// Terms followed by `: definition` lines, as in PHP Markdown Extra
+    pub definition_list: bool,
+    /// GitHub `> [!NOTE]` alerts and `::: kind Title` fenced admonitions
+    pub admonition: bool,
+    /// Generic `:name[label]{attributes}`, `::name` and `:::name` directives
+    pub directive: bool,
+    /// `[[Target]]`, `[[Target#Section]]` and `[[Target|Label]]` wiki links
+    pub wiki_link: bool,
+    /// GitHub `:name:` emoji shortcodes
+    pub emoji: bool,
+    /// `^superscript^`
+    pub superscript: bool,
+    /// `~subscript~`, a single tilde then no longer delimits strikethrough
+    pub subscript: bool,
+    /// `==highlighted==` text
+    pub mark: bool,
+    /// `++inserted++` text
+    pub inserted: bool,
+    /// `*[HTML]: HyperText Markup Language` abbreviation definitions, as in PHP Markdown Extra
+    pub abbreviation: bool,
+}
+
+impl Constructs {
+    /// Constructs defined by the CommonMark specification
+    pub fn commonmark() -> Self {
+        Self {
+            thematic_break: true,
+            atx_heading: true,
+            setext_heading: true,
+            indented_code_block: true,
+            fenced_code_block: true,
+            list: true,
+            html_flow: true,
+            html_text: true,
+            gfm_table: false,
+            gfm_strikethrough: false,
+            gfm_task_list_item: false,
+            gfm_autolink_literal: false,
+            gfm_footnote_definition: false,
+            gfm_footnote_reference: false,
+            front_matter: false,
+            math_text: false,
+            math_flow: false,
+            table_of_contents: false,
+            attributes: false,
+            definition_list: false,
+            admonition: false,
+            directive: false,
+            wiki_link: false,
+            emoji: false,
+            superscript: false,
+            subscript: false,
+            mark: false,
+            inserted: false,
+            abbreviation: false,
+        }
+    }
+
+    /// CommonMark constructs extended by GitHub Flavored Markdown
+    pub fn gfm() -> Self {
+        Self {
+            gfm_table: true,
+            gfm_strikethrough: true,
+            gfm_task_list_item: true,
+            gfm_autolink_literal: true,
+            gfm_footnote_definition: true,
+            gfm_footnote_reference: true,
+            ..Self::commonmark()
+        }
+    }
+}
+
+impl Default for Constructs {
+    fn default() -> Self {
+        Self::commonmark()
+    }
+}
+
+/// # Limits
+/// Upper bounds protecting the parser against pathological input
+///
+/// Input exceeding a limit is not rejected, the offending construct degrades to text instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Limits {
+    /// Maximum number of nested admonition and directive containers, deeper fences do not open
+    /// a container
+    pub max_nesting_depth: usize,
+    /// Maximum number of bytes parsed for structure, the remainder is kept as a text paragraph
+    pub max_document_size: usize,
+    /// Maximum number of inline delimiter runs tracked per block, further runs are treated as text
+    pub max_delimiter_stack: usize,
+    /// Maximum number of characters in the label of a footnote, wiki link, directive or
+    /// abbreviation, longer labels are treated as text
+    pub max_link_label_length: usize,
+    /// Maximum number of footnote and abbreviation definitions each, further definitions are
+    /// dropped and their references treated as text
+    pub max_reference_definitions: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_nesting_depth: 128,
+            max_document_size: usize::MAX,
+            max_delimiter_stack: 1024,
+            max_link_label_length: 999,
+            max_reference_definitions: 10_000,
+        }
+    }
+}
+
+/// # Parse options
+/// Configuration of the [Parser](crate::parser::Parser)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOptions {
+    pub constructs: Constructs,
+    pub limits: Limits,
+    /// Whether a single tilde delimits strikethrough in addition to a double tilde, unless it
+    /// delimits subscript
+    pub gfm_strikethrough_single_tilde: bool,
+    /// Whether headings are assigned a unique id derived from their text with GitHub's slug
+    /// algorithm
+    pub heading_ids: bool,
+    /// Whether straight quotes are curled, `--` and `---` become dashes and `...` an ellipsis
+    pub smart_punctuation: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::commonmark()
+    }
+}
+
+impl ParseOptions {
+    /// Strict CommonMark parsing
+    pub fn commonmark() -> Self {
+        Self {
+            constructs: Constructs::commonmark(),
+            limits: Limits::default(),
+            gfm_strikethrough_single_tilde: true,
+            heading_ids: false,
+            smart_punctuation: false,
+        }
+    }
+
+    /// GitHub Flavored Markdown parsing
+    pub fn gfm() -> Self {
+        Self {
+            constructs: Constructs::gfm(),
+            limits: Limits::default(),
+            gfm_strikethrough_single_tilde: true,
+            heading_ids: false,
+            smart_punctuation: false,
+        }
+    }
+
+    /// These options with their constructs adjusted by `configure`, to enable extensions on top
+    /// of [ParseOptions::commonmark] or [ParseOptions::gfm]
+    pub fn with_constructs(mut self, configure: impl FnOnce(&mut Constructs)) -> Self {
+        configure(&mut self.constructs);
+        self
+    }
+}
+
+/// # Table of contents options
+/// Heading levels listed in a [TableOfContents](crate::parser::document::toc::TableOfContents)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocOptions {
+    pub min_level: usize,
+    pub max_level: usize,
+}
+
+impl Default for TocOptions {
+    fn default() -> Self {
+        Self {
+            min_level: 1,
+            max_level: 6,
+        }
+    }
+}
+
+/// # Emoji rendering
+/// HTML output of an emoji shortcode
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum EmojiRendering {
+    /// The emoji itself
+    #[default]
+    Unicode,
+    /// `<span class="{class}" title=":name:">` around the emoji
+    Span {
+        class: String,
+    },
+    /// `<img class="{class}" title=":name:" alt="{emoji}" src="{base_url}{name}.png">`
+    Image {
+        class: String,
+        base_url: String,
+    },
+}
+
+/// # Render options
+/// Configuration of the HTML output of a [Document](crate::parser::document::Document)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderOptions {
+    /// Prefix of the class derived from the info string of a fenced code block
+    pub code_block_language_prefix: String,
+    /// Handlers rendering fenced code blocks by the language of their info string
+    pub code_block_handlers: CodeBlockHandlers,
+    /// Syntax highlighting of the fenced code blocks without a handler
+    pub highlighter: Option<Highlighter>,
+    /// Whether the info strings of fenced code blocks are parsed as
+    /// [CodeInfo](crate::parser::document::code_info::CodeInfo), splitting rustdoc attributes off
+    /// the language and wrapping lines as asked by `{1,3-5}` ranges and `linenos`
+    pub parse_code_info: bool,
+    /// Whether the leading `<` of tags GFM disallows in raw HTML is escaped
+    ///
+    /// The disallowed tags are `title`, `textarea`, `style`, `xmp`, `iframe`, `noembed`,
+    /// `noframes`, `script` and `plaintext`.
+    pub gfm_tagfilter: bool,
+    /// Whether headings with an id start with an empty `<a class="anchor">` linking to themselves
+    pub heading_anchor_links: bool,
+    /// Levels of the table of contents replacing a placeholder
+    pub table_of_contents: TocOptions,
+    /// Handlers rendering directives by name
+    pub directive_handlers: DirectiveHandlers,
+    /// Rendering of directives without a registered handler
+    pub unhandled_directives: UnhandledDirectives,
+    /// Resolver of the URLs of wiki links, the target followed by the slug of the section if unset
+    pub wiki_link_resolver: Option<WikiLinkResolver>,
+    /// Class added to wiki links the resolver does not resolve
+    pub wiki_link_broken_class: String,
+    /// Output of emoji shortcodes
+    pub emoji: EmojiRendering,
+}
+
+impl RenderOptions {
+    /// Output as rendered by GitHub
+    pub fn gfm() -> Self {
+        Self {
+            gfm_tagfilter: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            code_block_language_prefix: "language-".to_string(),
+            code_block_handlers: CodeBlockHandlers::default(),
+            highlighter: None,
+            parse_code_info: false,
+            gfm_tagfilter: false,
+            heading_anchor_links: false,
+            table_of_contents: TocOptions::default(),
+            directive_handlers: DirectiveHandlers::default(),
+            unhandled_directives: UnhandledDirectives::default(),
+            wiki_link_resolver: None,
+            wiki_link_broken_class: "wikilink-broken".to_string(),
+            emoji: EmojiRendering::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::Parser;
+
+    use super::{Constructs, ParseOptions, RenderOptions};
+
+    #[test]
+    /// Options: disabled constructs
+    fn test_options_disabled_construct() {
+        let options = ParseOptions {
+            constructs: Constructs {
+                atx_heading: false,
+                ..Constructs::commonmark()
+            },
+            ..ParseOptions::default()
+        };
+        let parser = Parser::from_reader_with_options("***\n# foo\n".as_bytes(), options);
+        assert_eq!("<hr />\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Options: code block language prefix
+    fn test_options_language_prefix() {
+        let options = RenderOptions {
+            code_block_language_prefix: "lang-".to_string(),
+            ..RenderOptions::default()
+        };
+        let parser = Parser::from_reader("```rust\nfoo\n```\n".as_bytes());
+        assert_eq!("<pre><code class=\"lang-rust\">foo\n</code></pre>\n", parser.parse_to_string_with(&options).unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Limits: document size
+    fn test_limits_document_size() {
+        let mut options = ParseOptions::default();
+        options.limits.max_document_size = 10;
+        let parser = Parser::from_reader_with_options("# foo\n***\n# bar\n".as_bytes(), options);
+        assert_eq!("<h1>foo</h1>\n<hr />\n<p># bar\n</p>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
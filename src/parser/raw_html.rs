@@ -0,0 +1,185 @@
+//! Scanning of raw HTML shared by HTML blocks and inline HTML
+
+use crate::unicode;
+
+/// Tag names of HTML blocks ending with their closing tag instead of a blank line
+pub const RAW_TEXT_TAGS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// Tag names of HTML blocks ending with a blank line
+pub const BLOCK_TAGS: [&str; 62] = [
+    "address", "article", "aside", "base", "basefont", "blockquote", "body", "caption", "center",
+    "col", "colgroup", "dd", "details", "dialog", "dir", "div", "dl", "dt", "fieldset",
+    "figcaption", "figure", "footer", "form", "frame", "frameset", "h1", "h2", "h3", "h4", "h5",
+    "h6", "head", "header", "hr", "html", "iframe", "legend", "li", "link", "main", "menu",
+    "menuitem", "nav", "noframes", "ol", "optgroup", "option", "p", "param", "search", "section",
+    "summary", "table", "tbody", "td", "tfoot", "th", "thead", "title", "tr", "track", "ul",
+];
+
+/// # Unterminated
+/// Kinds of raw HTML whose terminator does not occur in the rest of the source
+///
+/// Once a search for a terminator failed, every later search would fail as well, remembering
+/// this keeps scanning linear for sources full of unterminated openers.
+#[derive(Default)]
+pub struct Unterminated {
+    comment: bool,
+    processing_instruction: bool,
+    declaration: bool,
+    cdata: bool,
+}
+
+/// End of the inline raw HTML starting with the `<` at `start`
+pub fn inline(source: &[char], start: usize, unterminated: &mut Unterminated) -> Option<usize> {
+    if starts_with(source, start, "<!--") {
+        comment(source, start, unterminated)
+    } else if starts_with(source, start, "<?") {
+        terminated(source, start + 2, "?>", &mut unterminated.processing_instruction)
+    } else if starts_with(source, start, "<![CDATA[") {
+        terminated(source, start + 9, "]]>", &mut unterminated.cdata)
+    } else if starts_with(source, start, "<!") && source.get(start + 2).is_some_and(char::is_ascii_alphabetic) {
+        terminated(source, start + 2, ">", &mut unterminated.declaration)
+    } else {
+        open_tag(source, start).or_else(|| closing_tag(source, start))
+    }
+}
+
+/// `<` followed by a tag name, attributes, optional whitespace, an optional `/` and `>`
+pub fn open_tag(source: &[char], start: usize) -> Option<usize> {
+    if source.get(start) != Some(&'<') {
+        return None;
+    }
+
+    let mut end = tag_name(source, start + 1)?;
+    while let Some(attribute_end) = attribute(source, end) {
+        end = attribute_end;
+    }
+
+    end = whitespace(source, end);
+    if source.get(end) == Some(&'/') {
+        end += 1;
+    }
+
+    (source.get(end) == Some(&'>')).then_some(end + 1)
+}
+
+/// `</` followed by a tag name, optional whitespace and `>`
+pub fn closing_tag(source: &[char], start: usize) -> Option<usize> {
+    if !starts_with(source, start, "</") {
+        return None;
+    }
+
+    let end = whitespace(source, tag_name(source, start + 2)?);
+    (source.get(end) == Some(&'>')).then_some(end + 1)
+}
+
+/// End of the tag name starting at `start`, an ASCII letter followed by ASCII alphanumerics and
+/// hyphens
+pub fn tag_name(source: &[char], start: usize) -> Option<usize> {
+    if !source.get(start)?.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let length = source[start..].iter()
+        .take_while(|character| character.is_ascii_alphanumeric() || **character == '-')
+        .count();
+
+    Some(start + length)
+}
+
+pub fn starts_with(source: &[char], start: usize, prefix: &str) -> bool {
+    let mut characters = source.iter().skip(start);
+    prefix.chars().all(|expected| characters.next() == Some(&expected))
+}
+
+/// Whether `source` contains `pattern`, ignoring ASCII case
+pub fn contains_ignore_case(source: &[char], pattern: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+
+    source.windows(pattern.len())
+        .any(|window| window.iter().zip(&pattern).all(|(a, b)| a.eq_ignore_ascii_case(b)))
+}
+
+fn comment(source: &[char], start: usize, unterminated: &mut Unterminated) -> Option<usize> {
+    if starts_with(source, start, "<!-->") {
+        return Some(start + 5);
+    }
+
+    if starts_with(source, start, "<!--->") {
+        return Some(start + 6);
+    }
+
+    terminated(source, start + 4, "-->", &mut unterminated.comment)
+}
+
+/// End of the first `terminator` at or after `start`
+fn terminated(source: &[char], start: usize, terminator: &str, unterminated: &mut bool) -> Option<usize> {
+    if *unterminated {
+        return None;
+    }
+
+    let terminator = terminator.chars().collect::<Vec<_>>();
+    let end = source.get(start..)?
+        .windows(terminator.len())
+        .position(|window| window == terminator.as_slice())
+        .map(|index| start + index + terminator.len());
+
+    *unterminated = end.is_none();
+    end
+}
+
+/// Spaces, tabs and up to one line ending
+fn whitespace(source: &[char], start: usize) -> usize {
+    let mut end = start;
+    let mut line_ending = false;
+
+    while let Some(&character) = source.get(end) {
+        match character {
+            unicode::SPACE | unicode::TAB => {}
+            unicode::LINE_FEED if !line_ending => line_ending = true,
+            _ => break,
+        }
+        end += 1;
+    }
+
+    end
+}
+
+/// Whitespace, an attribute name and an optional value specification
+fn attribute(source: &[char], start: usize) -> Option<usize> {
+    let name_start = whitespace(source, start);
+    if name_start == start {
+        return None;
+    }
+
+    let first = source.get(name_start)?;
+    if !(first.is_ascii_alphabetic() || *first == '_' || *first == ':') {
+        return None;
+    }
+
+    let name_end = name_start + source[name_start..].iter()
+        .take_while(|character| character.is_ascii_alphanumeric() || "_.:-".contains(**character))
+        .count();
+
+    let equals = whitespace(source, name_end);
+    if source.get(equals) != Some(&'=') {
+        return Some(name_end);
+    }
+
+    attribute_value(source, whitespace(source, equals + 1))
+}
+
+fn attribute_value(source: &[char], start: usize) -> Option<usize> {
+    match source.get(start)? {
+        quote @ ('"' | '\'') => {
+            let length = source[start + 1..].iter().position(|character| character == quote)?;
+            Some(start + length + 2)
+        }
+        _ => {
+            let length = source[start..].iter()
+                .take_while(|character| !character.is_whitespace() && !"\"'=<>`".contains(**character))
+                .count();
+
+            (length > 0).then_some(start + length)
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::DeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::default::DefaultState;
 use crate::parser::state::potential::PotentialState;
 use crate::parser::transition::{Transition, TransitionEffect};
@@ -15,6 +18,13 @@ type HandlerTransitionEffect = DeterministicTransitionEffect<(), Option<Block>>;
 
 pub struct StateHandler(Option<State>);
 
+impl StateHandler {
+    /// Whether a block is running, started by the first character of a line that is not blank
+    pub fn in_block(&self) -> bool {
+        matches!(self.0, Some(State::Potential(_)))
+    }
+}
+
 impl Transition for &mut StateHandler {
     type Effect = HandlerTransitionEffect;
 
@@ -47,9 +57,9 @@ impl Transition for &mut StateHandler {
     }
 }
 
-impl Default for StateHandler {
-    fn default() -> Self {
-        Self(Some(Default::default()))
+impl From<ParseOptions> for StateHandler {
+    fn from(value: ParseOptions) -> Self {
+        Self(Some(State::from(Rc::new(value))))
     }
 }
 
@@ -67,11 +77,19 @@ pub enum State {
 }
 
 impl State {
-    pub fn from_leading_space_count(leading_spaces: usize) -> Self {
+    pub fn from_leading_space_count(leading_spaces: usize, options: Rc<ParseOptions>) -> Self {
         State::Default(
-            DefaultState::from(leading_spaces)
+            DefaultState::from((leading_spaces, options))
         )
     }
+
+    /// Whether the line run so far starts a block interrupting the one before it
+    pub fn interrupts(&self) -> bool {
+        match self {
+            State::Default(_) => false,
+            State::Potential(state) => state.interrupts(),
+        }
+    }
 }
 
 impl Transition for State {
@@ -99,8 +117,8 @@ impl Transition for State {
     }
 }
 
-impl Default for State {
-    fn default() -> Self {
-        State::Default(DefaultState::default())
+impl From<Rc<ParseOptions>> for State {
+    fn from(value: Rc<ParseOptions>) -> Self {
+        State::Default(DefaultState::from(value))
     }
 }
\ No newline at end of file
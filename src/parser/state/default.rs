@@ -1,31 +1,52 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::DeterministicTransitionEffect;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::potential::PotentialState;
+use crate::parser::state::sub_state::abbreviation_definition::AbbreviationDefinitionState;
+use crate::parser::state::sub_state::admonition::AdmonitionState;
 use crate::parser::state::sub_state::atx_heading::ATXHeadingState;
+use crate::parser::state::sub_state::definition_list::DefinitionListState;
+use crate::parser::state::sub_state::directive::DirectiveState;
 use crate::parser::state::sub_state::fenced_code_block::FencedCodeBlockState;
+use crate::parser::state::sub_state::footnote_definition::FootnoteDefinitionState;
+use crate::parser::state::sub_state::html_block::HtmlBlockState;
 use crate::parser::state::sub_state::indented_code_block::IndentedCodeBlockState;
+use crate::parser::state::sub_state::list::ListState;
 use crate::parser::state::sub_state::stx_heading::{STXHeadingState};
+use crate::parser::state::sub_state::table::TableState;
 use crate::parser::state::sub_state::thematic_break::ThematicBreakState;
+use crate::parser::state::sub_state::toc_placeholder::TocPlaceholderState;
 use crate::parser::state::sub_state::SubStates;
 use crate::parser::state::{LineEnding, State};
 use crate::parser::transition::{Transition, TransitionEffect};
 
 pub struct DefaultState {
     leading_spaces: usize,
+    options: Rc<ParseOptions>,
+    /// Block completed on the line that ended the previous block, emitted by the next transition
+    pending: Option<Block>,
 }
 
 impl Transition for DefaultState {
     type Effect = DeterministicTransitionEffect<State, Option<Block>>;
 
     fn transition(mut self, character: Character) -> Self::Effect {
+        if let Some(block) = self.pending.take() {
+            let (state, _) = self.transition(character).content();
+            return DeterministicTransitionEffect::new(state, Some(block));
+        }
+
         let mut states = SubStates::default();
+        let constructs = &self.options.constructs;
 
         self.leading_spaces += character.space_count();
 
-        if self.leading_spaces >= 4 {
+        if self.leading_spaces >= 4 && constructs.indented_code_block {
             states.push(
-                IndentedCodeBlockState::default()
+                IndentedCodeBlockState::from(self.options.clone())
             )
         }
 
@@ -34,49 +55,105 @@ impl Transition for DefaultState {
         }
 
         if self.leading_spaces < 4 {
-            states.push(
-                STXHeadingState::from(character.clone())
-            );
+            if constructs.setext_heading {
+                states.push(
+                    STXHeadingState::from((character.clone(), self.options.clone()))
+                );
+            }
+
+            if let (true, Ok(state)) = (constructs.thematic_break, ThematicBreakState::try_from(character.clone())) {
+                states.push(state);
+            }
+
+            if let (true, Ok(state)) = (constructs.atx_heading, ATXHeadingState::try_from((character.clone(), self.options.clone()))) {
+                states.push(state);
+            }
+
+            if let (true, Ok(state)) = (constructs.fenced_code_block, FencedCodeBlockState::try_from((self.leading_spaces, character.clone(), self.options.clone()))) {
+                states.push(state);
+            }
+
+            if let (true, Ok(state)) = (constructs.list, ListState::try_from((self.leading_spaces, character.clone(), self.options.clone()))) {
+                states.push(state);
+            }
+
+            if let (true, Ok(state)) = (constructs.html_flow, HtmlBlockState::try_from((self.leading_spaces, character.clone()))) {
+                states.push(state);
+            }
+
+            if let (true, Ok(state)) = (constructs.gfm_footnote_definition, FootnoteDefinitionState::try_from((character.clone(), self.options.clone()))) {
+                states.push(state);
+            }
+
+            if let (true, Ok(state)) = (constructs.table_of_contents, TocPlaceholderState::try_from(character.clone())) {
+                states.push(state);
+            }
+
+            if let (true, Ok(state)) = (constructs.definition_list, DefinitionListState::try_from((character.clone(), self.options.clone()))) {
+                states.push(state);
+            }
 
-            if let Ok(state) = ThematicBreakState::try_from(character.clone()) {
+            let nestable = self.options.limits.max_nesting_depth > 0;
+            if let (true, Ok(state)) = (constructs.admonition && nestable, AdmonitionState::try_from((character.clone(), self.options.clone()))) {
                 states.push(state);
             }
 
-            if let Ok(state) = ATXHeadingState::try_from(character.clone()) {
+            if let (true, Ok(state)) = (constructs.directive, DirectiveState::try_from((character.clone(), self.options.clone()))) {
                 states.push(state);
             }
 
-            if let Ok(state) = FencedCodeBlockState::try_from((self.leading_spaces, character.clone())) {
+            if let (true, Ok(state)) = (constructs.abbreviation, AbbreviationDefinitionState::try_from((character.clone(), self.options.clone()))) {
                 states.push(state);
             }
+
+            if constructs.gfm_table {
+                states.push(
+                    TableState::from((character.clone(), self.options.clone()))
+                );
+            }
         }
 
 
-        DeterministicTransitionEffect::transition_into::<PotentialState>(states)
+        DeterministicTransitionEffect::transition_into::<PotentialState>((states, self.options))
     }
 
     fn end_line(mut self, _: LineEnding) -> Self::Effect {
         self.leading_spaces = 0;
-        DeterministicTransitionEffect::pass(self)
+        let block = self.pending.take();
+        DeterministicTransitionEffect::new(self.into(), block)
     }
 
     fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
-        None
+        self.pending
     }
 }
 
-impl Default for DefaultState {
-    fn default() -> Self {
+impl From<Rc<ParseOptions>> for DefaultState {
+    fn from(value: Rc<ParseOptions>) -> Self {
         DefaultState {
-            leading_spaces: 0
+            leading_spaces: 0,
+            options: value,
+            pending: None,
+        }
+    }
+}
+
+impl From<(usize, Rc<ParseOptions>)> for DefaultState {
+    fn from((leading_spaces, options): (usize, Rc<ParseOptions>)) -> Self {
+        Self {
+            leading_spaces,
+            options,
+            pending: None,
         }
     }
 }
 
-impl From<usize> for DefaultState {
-    fn from(value: usize) -> Self {
+impl From<(Block, Rc<ParseOptions>)> for DefaultState {
+    fn from((pending, options): (Block, Rc<ParseOptions>)) -> Self {
         Self {
-            leading_spaces: value,
+            leading_spaces: 0,
+            options,
+            pending: Some(pending),
         }
     }
 }
@@ -25,6 +25,10 @@ where
         self.state
     }
 
+    pub fn get(&self) -> &State {
+        &self.state
+    }
+
     fn handle_effect<F>(mut self, effect: F) ->
     NonDeterministicTransitionEffect<SuperState, Option<Block>>
     where
@@ -1,38 +1,62 @@
 use std::cell::OnceCell;
+use std::rc::Rc;
 
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::DeterministicTransitionEffect;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::default::DefaultState;
 use crate::parser::state::{LineEnding, State};
 use crate::parser::state::sub_state::{SubState, SubStates};
 use crate::parser::transition::{Transition, TransitionEffect};
 
-
+/// # Potential state
+/// Candidates of the block starting at the current line, run side by side until one completes
+///
+/// [DefaultState] spawns at most one candidate per enabled block construct and a candidate never
+/// forks, so the work per character is bounded by the number of constructs rather than the input.
 pub struct PotentialState {
     states: SubStates,
+    options: Rc<ParseOptions>,
 }
 
 impl PotentialState {
-    fn handle_transition(self, mut result: OnceCell<Block>) -> DeterministicTransitionEffect<State, Option<Block>> {
-        if let Some(block) = result.take() {
-            let into = match self.states.last() {
-                Some(SubState::IntoSuper(_)) => match self.states.states().pop() {
-                    Some(SubState::IntoSuper(state)) => Some(state),
-                    _ => None,
-                },
+    pub fn interrupts(&self) -> bool {
+        self.states.interrupts()
+    }
+
+    /// State of the block following a candidate that handed its line over
+    fn handed_over(&mut self) -> Option<State> {
+        match self.states.last() {
+            Some(SubState::IntoSuper(_)) => match self.states.pop() {
+                Some(SubState::IntoSuper(state)) => Some(state),
                 _ => None,
-            };
+            },
+            _ => None,
+        }
+    }
 
-            match into {
+    fn handle_transition(mut self, mut result: OnceCell<Block>) -> DeterministicTransitionEffect<State, Option<Block>> {
+        if let Some(block) = result.take() {
+            match self.handed_over() {
                 Some(state) => DeterministicTransitionEffect::new(
                     state,
                     Some(block)
                 ),
-                None => DeterministicTransitionEffect::complete(block),
+                None => DeterministicTransitionEffect::new(
+                    DefaultState::from(self.options).into(),
+                    Some(block),
+                ),
             }
 
         } else if self.states.is_empty() {
-            DeterministicTransitionEffect::dismiss()
+            DeterministicTransitionEffect::pass(DefaultState::from(self.options))
+        } else if self.states.len() == 1 {
+            // The last candidate may hand its line over without completing a block
+            match self.handed_over() {
+                Some(state) => DeterministicTransitionEffect::pass(state),
+                None => DeterministicTransitionEffect::pass(self),
+            }
         } else {
             DeterministicTransitionEffect::pass(self)
         }
@@ -59,6 +83,7 @@ impl Transition for PotentialState {
 
         }
 
+        retained_states.retain_claimed();
         self.states = retained_states;
 
         self.handle_transition(completion)
@@ -81,6 +106,7 @@ impl Transition for PotentialState {
 
         }
 
+        retained_states.retain_claimed();
         self.states = retained_states;
 
         self.handle_transition(completion)
@@ -102,10 +128,11 @@ impl Transition for PotentialState {
     }
 }
 
-impl From<SubStates> for PotentialState {
-    fn from(value: SubStates) -> Self {
+impl From<(SubStates, Rc<ParseOptions>)> for PotentialState {
+    fn from((states, options): (SubStates, Rc<ParseOptions>)) -> Self {
         Self {
-            states: value,
+            states,
+            options,
         }
     }
 }
@@ -2,11 +2,20 @@ use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::state::sub_state::abbreviation_definition::AbbreviationDefinitionState;
+use crate::parser::state::sub_state::admonition::AdmonitionState;
 use crate::parser::state::sub_state::atx_heading::ATXHeadingState;
+use crate::parser::state::sub_state::definition_list::DefinitionListState;
+use crate::parser::state::sub_state::directive::DirectiveState;
 use crate::parser::state::sub_state::fenced_code_block::FencedCodeBlockState;
+use crate::parser::state::sub_state::footnote_definition::FootnoteDefinitionState;
+use crate::parser::state::sub_state::html_block::HtmlBlockState;
+use crate::parser::state::sub_state::list::ListState;
 use crate::parser::state::sub_state::indented_code_block::IndentedCodeBlockState;
 use crate::parser::state::sub_state::stx_heading::{STXHeadingState};
+use crate::parser::state::sub_state::table::TableState;
 use crate::parser::state::sub_state::thematic_break::ThematicBreakState;
+use crate::parser::state::sub_state::toc_placeholder::TocPlaceholderState;
 use crate::parser::state::State;
 use crate::parser::transition::{Transition, TransitionEffect};
 
@@ -15,6 +24,17 @@ pub(crate) mod atx_heading;
 pub(crate) mod indented_code_block;
 pub(crate) mod stx_heading;
 pub(crate) mod thematic_break;
+pub(crate) mod table;
+pub(crate) mod html_block;
+pub(crate) mod footnote_definition;
+pub(crate) mod toc_placeholder;
+pub(crate) mod definition_list;
+pub(crate) mod admonition;
+pub(crate) mod colon_fence;
+pub(crate) mod directive;
+pub(crate) mod abbreviation_definition;
+pub(crate) mod list;
+pub(crate) mod interruption;
 
 
 pub enum SubState {
@@ -23,9 +43,43 @@ pub enum SubState {
     ThematicBreak(ThematicBreakState),
     IndentedCodeBlock(IndentedCodeBlockState),
     FencedCodeBlock(FencedCodeBlockState),
+    Table(TableState),
+    HtmlBlock(HtmlBlockState),
+    FootnoteDefinition(FootnoteDefinitionState),
+    TocPlaceholder(TocPlaceholderState),
+    DefinitionList(DefinitionListState),
+    Admonition(AdmonitionState),
+    Directive(DirectiveState),
+    AbbreviationDefinition(AbbreviationDefinitionState),
+    List(ListState),
     IntoSuper(State),
 }
 
+impl SubState {
+    /// Whether the state has ruled out all other candidates for the current block
+    pub fn claims(&self) -> bool {
+        match self {
+            SubState::Table(state) => state.get().claims(),
+            SubState::HtmlBlock(state) => state.get().claims(),
+            SubState::FootnoteDefinition(state) => state.get().claims(),
+            SubState::DefinitionList(state) => state.get().claims(),
+            SubState::Admonition(state) => state.get().claims(),
+            SubState::Directive(state) => state.get().claims(),
+            SubState::List(state) => state.get().claims(),
+            _ => false,
+        }
+    }
+
+    /// Whether the state starts a block interrupting a table body or a paragraph, unlike the
+    /// constructs that continue the text of the line before them
+    pub fn interrupts(&self) -> bool {
+        match self {
+            SubState::List(state) => state.get().interrupts(),
+            _ => !matches!(self, SubState::STXHeading(_) | SubState::Table(_) | SubState::DefinitionList(_) | SubState::IntoSuper(_)),
+        }
+    }
+}
+
 impl Transition for SubState {
     type Effect = NonDeterministicTransitionEffect<SubState, Option<Block>>;
 
@@ -36,6 +90,15 @@ impl Transition for SubState {
             SubState::ThematicBreak(state) => state.transition(character),
             SubState::IndentedCodeBlock(state) => state.transition(character),
             SubState::FencedCodeBlock(state) => state.transition(character),
+            SubState::Table(state) => state.transition(character),
+            SubState::HtmlBlock(state) => state.transition(character),
+            SubState::FootnoteDefinition(state) => state.transition(character),
+            SubState::TocPlaceholder(state) => state.transition(character),
+            SubState::DefinitionList(state) => state.transition(character),
+            SubState::List(state) => state.transition(character),
+            SubState::Admonition(state) => state.transition(character),
+            SubState::Directive(state) => state.transition(character),
+            SubState::AbbreviationDefinition(state) => state.transition(character),
             SubState::IntoSuper(_) => NonDeterministicTransitionEffect::dismiss(),
         }
     }
@@ -47,6 +110,15 @@ impl Transition for SubState {
             SubState::ThematicBreak(state) => state.end_line(line_ending),
             SubState::IndentedCodeBlock(state) => state.end_line(line_ending),
             SubState::FencedCodeBlock(state) => state.end_line(line_ending),
+            SubState::Table(state) => state.end_line(line_ending),
+            SubState::HtmlBlock(state) => state.end_line(line_ending),
+            SubState::FootnoteDefinition(state) => state.end_line(line_ending),
+            SubState::TocPlaceholder(state) => state.end_line(line_ending),
+            SubState::DefinitionList(state) => state.end_line(line_ending),
+            SubState::List(state) => state.end_line(line_ending),
+            SubState::Admonition(state) => state.end_line(line_ending),
+            SubState::Directive(state) => state.end_line(line_ending),
+            SubState::AbbreviationDefinition(state) => state.end_line(line_ending),
             SubState::IntoSuper(_) => NonDeterministicTransitionEffect::dismiss(),
         }
     }
@@ -58,6 +130,15 @@ impl Transition for SubState {
             SubState::ThematicBreak(state) => state.end(),
             SubState::IndentedCodeBlock(state) => state.end(),
             SubState::FencedCodeBlock(state) => state.end(),
+            SubState::Table(state) => state.end(),
+            SubState::HtmlBlock(state) => state.end(),
+            SubState::FootnoteDefinition(state) => state.end(),
+            SubState::TocPlaceholder(state) => state.end(),
+            SubState::DefinitionList(state) => state.end(),
+            SubState::List(state) => state.end(),
+            SubState::Admonition(state) => state.end(),
+            SubState::Directive(state) => state.end(),
+            SubState::AbbreviationDefinition(state) => state.end(),
             SubState::IntoSuper(_) => None,
         }
     }
@@ -78,9 +159,28 @@ impl SubStates {
         self.0.last()
     }
 
+    pub fn pop(&mut self) -> Option<SubState> {
+        self.0.pop()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    pub fn interrupts(&self) -> bool {
+        self.0.iter().any(SubState::interrupts)
+    }
+
+    /// Drops all candidates once one of them claims the block
+    pub fn retain_claimed(&mut self) {
+        if self.0.iter().any(SubState::claims) {
+            self.0.retain(SubState::claims);
+        }
+    }
 }
 
 impl Default for SubStates {
@@ -0,0 +1,94 @@
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::LineEnding;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+const ASTERISK: char = '*';
+const OPENING_BRACKET: char = '[';
+const CLOSING_BRACKET: char = ']';
+const COLON: char = ':';
+
+/// # Abbreviation definition
+/// `*[label]: title` on a single line, the title being the rest of the line
+///
+/// The label is not empty, contains no bracket and is at most as long as a link label.
+///
+/// ## Completion
+/// * LineEnding or DocumentEnding following a complete `*[label]:` marker
+///
+/// ## Dismissal
+/// * any character other than [OPENING_BRACKET] following the [ASTERISK]
+/// * LineEnding or DocumentEnding without a complete marker
+pub struct AbbreviationDefinitionState {
+    options: Rc<ParseOptions>,
+    line: String,
+}
+
+impl AbbreviationDefinitionState {
+    fn definition(&self) -> Option<Leaf> {
+        let rest = self.line.strip_prefix(ASTERISK)?.strip_prefix(OPENING_BRACKET)?;
+        let (label, title) = rest.split_once(CLOSING_BRACKET)?;
+        let title = title.strip_prefix(COLON)?;
+
+        let valid = !label.trim().is_empty()
+            && !label.contains(OPENING_BRACKET)
+            && label.chars().count() <= self.options.limits.max_link_label_length;
+
+        valid.then(|| Leaf::AbbreviationDefinition {
+            label: label.to_string(),
+            title: title.trim().to_string(),
+        })
+    }
+}
+
+impl Transition for AbbreviationDefinitionState {
+    type Effect = NonDeterministicTransitionEffect<SubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if self.line.len() == 1 && !matches!(character, Character::Unescaped(OPENING_BRACKET)) {
+            return NonDeterministicTransitionEffect::dismiss();
+        }
+
+        self.line.push_str(&character.to_raw_string());
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        match self.definition() {
+            Some(definition) => NonDeterministicTransitionEffect::complete::<Block>(definition),
+            None => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        self.definition()
+            .map(Leaf::into)
+    }
+}
+
+impl TryFrom<(Character, Rc<ParseOptions>)> for AbbreviationDefinitionState {
+    type Error = Error;
+
+    fn try_from((character, options): (Character, Rc<ParseOptions>)) -> Result<Self, Self::Error> {
+        match character {
+            Character::Unescaped(ASTERISK) => Ok(Self {
+                options,
+                line: ASTERISK.to_string(),
+            }),
+            _ => Err(Error::Conversion),
+        }
+    }
+}
+
+impl From<AbbreviationDefinitionState> for SubState {
+    fn from(value: AbbreviationDefinitionState) -> Self {
+        SubState::AbbreviationDefinition(value)
+    }
+}
@@ -0,0 +1,232 @@
+mod alert;
+mod fence;
+mod content;
+mod newline;
+
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::character_parser;
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::handler::Handler;
+use crate::parser::state::sub_state::admonition::alert::AlertMarkerState;
+use crate::parser::state::sub_state::admonition::content::ContentState;
+use crate::parser::state::sub_state::admonition::fence::FenceOpeningState;
+use crate::parser::state::sub_state::admonition::newline::NewlineState;
+use crate::parser::state::sub_state::colon_fence::{Fences, COLON};
+use crate::parser::state::sub_state::SubState;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+const GREATER_THAN: char = '>';
+
+pub type AdmonitionState = Handler<SubState, AdmonitionSubState>;
+
+impl TryFrom<(Character, Rc<ParseOptions>)> for AdmonitionState {
+    type Error = Error;
+
+    fn try_from((character, options): (Character, Rc<ParseOptions>)) -> Result<Self, Self::Error> {
+        let state = match character {
+            Character::Unescaped(GREATER_THAN) => AlertMarkerState::new(options).into(),
+            Character::Unescaped(COLON) => FenceOpeningState::new(options).into(),
+            _ => return Err(Error::StartState),
+        };
+
+        Ok(AdmonitionState::new(state))
+    }
+}
+
+impl From<AdmonitionState> for SubState {
+    fn from(value: AdmonitionState) -> Self {
+        value.state().into()
+    }
+}
+
+/// Syntax of an admonition, determining how its lines continue and end
+#[derive(Clone, Copy)]
+pub enum Style {
+    /// GitHub alert, a block quote starting with a `[!KIND]` line
+    Alert,
+    /// `:::` fenced container, ended by a closing fence outside of the fences opened within
+    Fenced(Fences),
+}
+
+/// # Body
+/// Kind, title and raw lines of an admonition, its lines are parsed as blocks on completion
+pub struct Body {
+    pub(crate) kind: String,
+    pub(crate) title: Option<String>,
+    pub(crate) lines: Vec<String>,
+}
+
+impl Body {
+    pub fn new(kind: String, title: Option<String>) -> Self {
+        Self {
+            kind,
+            title,
+            lines: vec![],
+        }
+    }
+
+    /// Admonition container, nested admonitions count against the nesting depth limit
+    fn block(self, options: &ParseOptions) -> Block {
+        let mut options = options.clone();
+        options.limits.max_nesting_depth = options.limits.max_nesting_depth.saturating_sub(1);
+
+        Container::new(
+            ContainerKind::Admonition {
+                kind: self.kind,
+                title: self.title,
+            },
+            character_parser::parse_blocks(&self.lines.join("\n"), options),
+        ).into()
+    }
+}
+
+pub enum AdmonitionSubState {
+    Alert(AlertMarkerState),
+    Fence(FenceOpeningState),
+    Content(ContentState),
+    NewLine(NewlineState),
+    Complete(State),
+}
+
+impl AdmonitionSubState {
+    /// An admonition claims the block once its opening line is complete
+    pub fn claims(&self) -> bool {
+        matches!(self, AdmonitionSubState::Content(_) | AdmonitionSubState::NewLine(_))
+    }
+
+    pub fn complete(body: Body, options: &ParseOptions) -> NonDeterministicTransitionEffect<AdmonitionSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::complete::<Block>(body.block(options))
+    }
+}
+
+impl Transition for AdmonitionSubState {
+    type Effect = NonDeterministicTransitionEffect<AdmonitionSubState, Option<Block>>;
+
+    fn transition(self, character: Character) -> Self::Effect {
+        match self {
+            AdmonitionSubState::Alert(state) => state.transition(character),
+            AdmonitionSubState::Fence(state) => state.transition(character),
+            AdmonitionSubState::Content(state) => state.transition(character),
+            AdmonitionSubState::NewLine(state) => state.transition(character),
+            AdmonitionSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        match self {
+            AdmonitionSubState::Alert(state) => state.end_line(line_ending),
+            AdmonitionSubState::Fence(state) => state.end_line(line_ending),
+            AdmonitionSubState::Content(state) => state.end_line(line_ending),
+            AdmonitionSubState::NewLine(state) => state.end_line(line_ending),
+            AdmonitionSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self {
+            AdmonitionSubState::Alert(state) => state.end(),
+            AdmonitionSubState::Fence(state) => state.end(),
+            AdmonitionSubState::Content(state) => state.end(),
+            AdmonitionSubState::NewLine(state) => state.end(),
+            AdmonitionSubState::Complete(_) => None,
+        }
+    }
+}
+
+impl From<AdmonitionSubState> for SubState {
+    fn from(value: AdmonitionSubState) -> Self {
+        match value {
+            AdmonitionSubState::Complete(state) => SubState::IntoSuper(state),
+            state => SubState::Admonition(
+                AdmonitionState::new(state)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::container::{Container, ContainerKind};
+    use crate::parser::options::{ParseOptions, RenderOptions};
+    use crate::parser::Parser;
+
+    #[test]
+    /// Admonitions (extension): alerts
+    fn test_admonition_alert() {
+        let parser = Parser::from_reader_with_options("> [!Warning]\n> # Heading\n>\n> ```\n> code\n> ```\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.admonition = true));
+        assert_eq!(
+            "<div class=\"markdown-alert markdown-alert-warning\">\n<p class=\"markdown-alert-title\">Warning</p>\n<h1>Heading</h1>\n<pre><code>code\n</code></pre>\n</div>\n<h1>After</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Admonitions (extension): colon fences
+    fn test_admonition_fenced() {
+        let parser = Parser::from_reader_with_options("::: note Read <this>\n# Inside\n\n::: tip\n## Nested\n:::\n:::\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.admonition = true));
+        let document = parser.parse().unwrap();
+
+        match document.blocks() {
+            [Block::Container(Container { kind: ContainerKind::Admonition { kind, title }, content }), _] => {
+                assert_eq!("note", kind);
+                assert_eq!(Some("Read <this>"), title.as_deref());
+                assert_eq!(2, content.len());
+            }
+            blocks => panic!("unexpected blocks {blocks:?}"),
+        }
+
+        assert_eq!(
+            "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Read &lt;this&gt;</p>\n<h1>Inside</h1>\n\
+            <div class=\"markdown-alert markdown-alert-tip\">\n<p class=\"markdown-alert-title\">Tip</p>\n<h2>Nested</h2>\n</div>\n</div>\n<h1>After</h1>\n",
+            document.to_html(&RenderOptions::default())
+        );
+    }
+
+    #[test]
+    /// Admonitions (extension): colon fences within a code fence neither close nor open a container
+    fn test_admonition_code_fence() {
+        let parser = Parser::from_reader_with_options("::: note\n```\n:::\n```\n:::\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.admonition = true));
+        assert_eq!(
+            "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<pre><code>:::\n</code></pre>\n</div>\n<h1>After</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+
+        let parser = Parser::from_reader_with_options("::: note\n~~~~\n::: tip\n~~~\n~~~~\n:::\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.admonition = true));
+        assert_eq!(
+            "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<pre><code>::: tip\n~~~\n</code></pre>\n</div>\n<h1>After</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Admonitions (extension): unknown kinds or disabled
+    fn test_admonition_not_recognized() {
+        let parser = Parser::from_reader_with_options("> [!NOTICE]\n> # Inside\n\n:: note\n\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.admonition = true));
+        assert_eq!("<h1>After</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+
+        let parser = Parser::from_reader_with_options("> [!NOTE]\n\n# After\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>After</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Admonitions (extension): nesting depth limit
+    fn test_admonition_nesting_depth() {
+        let mut options = ParseOptions::gfm().with_constructs(|constructs| constructs.admonition = true);
+        options.limits.max_nesting_depth = 1;
+
+        let parser = Parser::from_reader_with_options("::: note\n::: tip\n\n# Inside\n:::\n:::\n".as_bytes(), options);
+        assert_eq!(
+            "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<h1>Inside</h1>\n</div>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::admonition::newline::NewlineState;
+use crate::parser::state::sub_state::admonition::{AdmonitionSubState, Body};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// Kinds of GitHub alerts
+const KINDS: [&str; 5] = ["note", "tip", "important", "warning", "caution"];
+
+/// Length of the longest marker, `[!IMPORTANT]`, surrounded by a space each
+const MAX_MARKER_LENGTH: usize = 14;
+
+/// # Admonition: Alert marker
+/// First line of a block quote, consisting of a `[!KIND]` marker only
+///
+/// The kind is matched case-insensitively.
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding following a marker
+///
+/// ## Completion
+/// * DocumentEnding following a marker
+///
+/// ## Dismissal
+/// * line exceeding the length of a marker
+/// * LineEnding following anything but a marker
+pub struct AlertMarkerState {
+    pub(crate) options: Rc<ParseOptions>,
+    line: String,
+}
+
+impl AlertMarkerState {
+    pub fn new(options: Rc<ParseOptions>) -> Self {
+        Self {
+            options,
+            line: String::new(),
+        }
+    }
+
+    fn body(&self) -> Option<Body> {
+        let kind = self.line.trim()
+            .strip_prefix("[!")?
+            .strip_suffix(']')?
+            .to_lowercase();
+
+        KINDS.contains(&kind.as_str())
+            .then(|| Body::new(kind, None))
+    }
+}
+
+impl Transition for AlertMarkerState {
+    type Effect = NonDeterministicTransitionEffect<AdmonitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push_str(&character.to_raw_string());
+
+        match self.line.len() > MAX_MARKER_LENGTH {
+            true => NonDeterministicTransitionEffect::dismiss(),
+            false => NonDeterministicTransitionEffect::pass(self),
+        }
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        match self.body() {
+            Some(body) => NonDeterministicTransitionEffect::transition_into::<NewlineState>((body, self.options)),
+            None => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        let body = self.body()?;
+
+        AdmonitionSubState::complete(body, &self.options)
+            .end()
+    }
+}
+
+impl From<AlertMarkerState> for AdmonitionSubState {
+    fn from(value: AlertMarkerState) -> Self {
+        AdmonitionSubState::Alert(value)
+    }
+}
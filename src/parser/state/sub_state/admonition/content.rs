@@ -0,0 +1,107 @@
+use std::mem;
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::admonition::newline::NewlineState;
+use crate::parser::state::sub_state::admonition::{AdmonitionSubState, Body, Style};
+use crate::parser::state::sub_state::colon_fence::Fences;
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+/// # Admonition: Content
+/// Line of the admonition, kept raw to be parsed as blocks
+///
+/// A line of an alert follows the `>` marker, a single space following the marker is skipped.
+/// Within a fenced admonition, every fence opening a nested container has to be closed before a
+/// closing fence ends the admonition, and fences within a code fence are ignored.
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding within an alert
+///
+/// ## Completion
+/// * LineEnding of the closing fence of a fenced admonition
+/// * DocumentEnding
+pub struct ContentState {
+    pub(crate) body: Body,
+    pub(crate) style: Style,
+    pub(crate) options: Rc<ParseOptions>,
+    line: String,
+}
+
+impl Transition for ContentState {
+    type Effect = NonDeterministicTransitionEffect<AdmonitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        let marker_space = matches!(self.style, Style::Alert)
+            && self.line.is_empty()
+            && matches!(character, Character::Unescaped(unicode::SPACE));
+
+        if !marker_space {
+            self.line.push_str(&character.to_raw_string());
+        }
+
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        let line = mem::take(&mut self.line);
+
+        match &mut self.style {
+            Style::Alert => {
+                self.body.lines.push(line);
+                NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+            }
+            Style::Fenced(fences) if fences.is_closed_by(&line) => {
+                AdmonitionSubState::complete(self.body, &self.options)
+            }
+            Style::Fenced(fences) => {
+                fences.push(&line);
+                self.body.lines.push(line);
+                NonDeterministicTransitionEffect::pass(self)
+            }
+        }
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        let closed = matches!(self.style, Style::Fenced(fences) if fences.is_closed_by(&self.line));
+
+        if !closed {
+            self.body.lines.push(self.line);
+        }
+
+        AdmonitionSubState::complete(self.body, &self.options)
+            .end()
+    }
+}
+
+impl From<(Body, Rc<ParseOptions>)> for ContentState {
+    fn from((body, options): (Body, Rc<ParseOptions>)) -> Self {
+        Self {
+            body,
+            style: Style::Fenced(Fences::default()),
+            options,
+            line: String::new(),
+        }
+    }
+}
+
+impl From<NewlineState> for ContentState {
+    fn from(value: NewlineState) -> Self {
+        Self {
+            body: value.body,
+            style: Style::Alert,
+            options: value.options,
+            line: String::new(),
+        }
+    }
+}
+
+impl From<ContentState> for AdmonitionSubState {
+    fn from(value: ContentState) -> Self {
+        AdmonitionSubState::Content(value)
+    }
+}
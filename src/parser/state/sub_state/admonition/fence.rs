@@ -0,0 +1,94 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::admonition::content::ContentState;
+use crate::parser::state::sub_state::admonition::{AdmonitionSubState, Body};
+use crate::parser::state::sub_state::colon_fence::{self, COLON, MIN_FENCE_LENGTH};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Admonition: Opening fence
+/// `::: kind Title` line opening a fenced admonition
+///
+/// The kind is the first word following the fence, the rest of the line is the title. Colons
+/// closing the line, as in `::: kind :::`, are ignored. While directives are recognized, the kind
+/// has to be separated from the fence, `:::name` opens a container directive.
+///
+/// ## Transition
+/// * [ContentState] on LineEnding following a kind
+///
+/// ## Dismissal
+/// * any character other than [COLON] before 3 [COLON]
+/// * LineEnding without kind
+/// * LineEnding with a kind following the fence immediately while directives are recognized
+/// * DocumentEnding
+pub struct FenceOpeningState {
+    pub(crate) options: Rc<ParseOptions>,
+    line: String,
+}
+
+impl FenceOpeningState {
+    pub fn new(options: Rc<ParseOptions>) -> Self {
+        Self {
+            options,
+            line: COLON.to_string(),
+        }
+    }
+}
+
+impl Transition for FenceOpeningState {
+    type Effect = NonDeterministicTransitionEffect<AdmonitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if self.line.len() < MIN_FENCE_LENGTH && !matches!(character, Character::Unescaped(COLON)) {
+            return NonDeterministicTransitionEffect::dismiss();
+        }
+
+        self.line.push_str(&character.to_raw_string());
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        let separated = colon_fence::fence(&self.line)
+            .is_some_and(|rest| rest.starts_with(char::is_whitespace));
+        if self.options.constructs.directive && !separated {
+            return NonDeterministicTransitionEffect::dismiss();
+        }
+
+        match opening(&self.line) {
+            Some(body) => NonDeterministicTransitionEffect::transition_into::<ContentState>((body, self.options)),
+            None => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        None
+    }
+}
+
+impl From<FenceOpeningState> for AdmonitionSubState {
+    fn from(value: FenceOpeningState) -> Self {
+        AdmonitionSubState::Fence(value)
+    }
+}
+
+/// Kind and title of an opening fence line
+pub fn opening(line: &str) -> Option<Body> {
+    let rest = colon_fence::fence(line)?
+        .trim_end()
+        .trim_end_matches(COLON);
+    let (kind, title) = rest.trim()
+        .split_once(char::is_whitespace)
+        .unwrap_or((rest.trim(), ""));
+
+    let valid = !kind.is_empty() && kind.chars()
+        .all(|character| character.is_alphanumeric() || character == '-' || character == '_');
+
+    valid.then(|| Body::new(
+        kind.to_lowercase(),
+        Some(title.trim().to_string()).filter(|title| !title.is_empty()),
+    ))
+}
@@ -0,0 +1,87 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::admonition::content::ContentState;
+use crate::parser::state::sub_state::admonition::{AdmonitionSubState, Body, GREATER_THAN};
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Admonition: Newline
+/// Start of a line following a line of an alert
+///
+/// ## Transition
+/// * [ContentState] on `>` before an equivalent of 4 spaces
+///
+/// ## Completion
+/// * any other non-whitespace character, the line is handed back
+/// * LineEnding
+/// * DocumentEnding
+pub struct NewlineState {
+    pub(crate) body: Body,
+    pub(crate) options: Rc<ParseOptions>,
+    leading_spaces: usize,
+}
+
+impl Transition for NewlineState {
+    type Effect = NonDeterministicTransitionEffect<AdmonitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.leading_spaces += character.space_count();
+
+        match character {
+            Character::Unescaped(GREATER_THAN) if self.leading_spaces < 4 => {
+                NonDeterministicTransitionEffect::transition_into::<ContentState>(self)
+            }
+            character if character.is_blank() => NonDeterministicTransitionEffect::pass(self),
+            character => {
+                let (state, _) = State::from_leading_space_count(self.leading_spaces, self.options.clone())
+                    .transition(character)
+                    .content();
+
+                NonDeterministicTransitionEffect::new(
+                    AdmonitionSubState::Complete(state),
+                    Some(self.body.block(&self.options)),
+                )
+            }
+        }
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        AdmonitionSubState::complete(self.body, &self.options)
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        AdmonitionSubState::complete(self.body, &self.options)
+            .end()
+    }
+}
+
+impl From<(Body, Rc<ParseOptions>)> for NewlineState {
+    fn from((body, options): (Body, Rc<ParseOptions>)) -> Self {
+        Self {
+            body,
+            options,
+            leading_spaces: 0,
+        }
+    }
+}
+
+impl From<ContentState> for NewlineState {
+    fn from(value: ContentState) -> Self {
+        Self {
+            body: value.body,
+            options: value.options,
+            leading_spaces: 0,
+        }
+    }
+}
+
+impl From<NewlineState> for AdmonitionSubState {
+    fn from(value: NewlineState) -> Self {
+        AdmonitionSubState::NewLine(value)
+    }
+}
@@ -4,11 +4,17 @@ mod content;
 mod trailing;
 mod closing;
 
+use std::rc::Rc;
+
 use crate::error::Error;
 use crate::parser::character::Character;
+use crate::parser::document::attributes::Attributes;
 use crate::parser::document::block::Block;
+use crate::parser::document::heading::HeadingMeta;
+use crate::parser::document::inline::Inline;
 use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::handler::Handler;
 use crate::parser::state::sub_state::atx_heading::closing::PotentiallyClosingState;
 use crate::parser::state::sub_state::atx_heading::content::ContentState;
@@ -18,18 +24,24 @@ use crate::parser::state::sub_state::atx_heading::trailing::PotentiallyTrailingS
 use crate::parser::state::sub_state::SubState;
 use crate::parser::state::LineEnding;
 use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
 
 pub const HASHTAG: char = '#';
 
 pub type ATXHeadingState = Handler<SubState, ATXHeadingSubState>;
 
-impl TryFrom<Character> for ATXHeadingState {
+impl TryFrom<(Character, Rc<ParseOptions>)> for ATXHeadingState {
     type Error = Error;
 
-    fn try_from(value: Character) -> Result<Self, Self::Error> {
-        if let Character::Unescaped(HASHTAG) = value {
+    fn try_from((character, options): (Character, Rc<ParseOptions>)) -> Result<Self, Self::Error> {
+        if let Character::Unescaped(HASHTAG) = character {
             Ok(
-                Handler::new(ATXHeadingSubState::default())
+                Handler::new(ATXHeadingSubState::Opening(
+                    OpeningSequenceState {
+                        level: 1,
+                        options,
+                    },
+                ))
             )
         } else {
             Err(Error::StartState)
@@ -62,6 +74,16 @@ impl ATXHeadingSubState {
         }
     }
 
+    fn options(&self) -> &ParseOptions {
+        match self {
+            ATXHeadingSubState::Opening(state) => &state.options,
+            ATXHeadingSubState::LeadingWhitespace(state) => &state.options,
+            ATXHeadingSubState::Content(state) => &state.options,
+            ATXHeadingSubState::PotentiallyTrailing(state) => &state.options,
+            ATXHeadingSubState::PotentiallyClosing(state) => &state.options,
+        }
+    }
+
     fn content(self) -> String {
         match self {
             ATXHeadingSubState::Content(state) => state.content,
@@ -73,9 +95,22 @@ impl ATXHeadingSubState {
 
     fn complete(state: impl Into<ATXHeadingSubState>) -> NonDeterministicTransitionEffect<ATXHeadingSubState, Option<Block>> {
         let state = state.into();
+        let level = state.level();
+        let with_attributes = state.options().constructs.attributes;
+        let mut content = state.content();
+        let mut attributes = Attributes::default();
+
+        if with_attributes {
+            if let Some((text, parsed)) = Attributes::split_trailing(&content) {
+                content = strip_closing_sequence(text).to_string();
+                attributes = parsed;
+            }
+        }
+
         NonDeterministicTransitionEffect::complete::<Block>(Leaf::AtxHeading {
-            level: state.level(),
-            text: state.content(),
+            level,
+            content: vec![Inline::Raw(content)],
+            meta: HeadingMeta::from_attributes(attributes),
         })
     }
 }
@@ -114,12 +149,16 @@ impl Transition for ATXHeadingSubState {
     }
 }
 
-impl Default for ATXHeadingSubState {
-    fn default() -> Self {
-        ATXHeadingSubState::Opening(
-            OpeningSequenceState {
-                level: 1,
-            },
-        )
+/// Strips a closing sequence left in front of a trailing attribute block
+///
+/// The phases only recognize a closing sequence at the very end of the line, `# Title ## {#id}`
+/// keeps `Title ##` as content. Like at the end of the line, the sequence has to be preceded by a
+/// space or tab unless it is the whole content.
+fn strip_closing_sequence(content: &str) -> &str {
+    let text = content.trim_end_matches(HASHTAG);
+
+    match text.is_empty() || text.ends_with([unicode::SPACE, unicode::TAB]) {
+        true if text.len() < content.len() => text.trim_end(),
+        _ => content,
     }
-}
\ No newline at end of file
+}
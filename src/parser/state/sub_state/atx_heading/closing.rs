@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::atx_heading::{ATXHeadingSubState, HASHTAG};
 use crate::parser::state::sub_state::atx_heading::content::ContentState;
 use crate::parser::state::sub_state::atx_heading::leading::LeadingWhitespaceState;
@@ -23,6 +26,7 @@ pub struct PotentiallyClosingState {
     pub(crate) level: usize,
     pub(crate) content: String,
     pub(crate) temp: String,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for PotentiallyClosingState {
@@ -40,7 +44,7 @@ impl Transition for PotentiallyClosingState {
             }
             character => {
                 self.content.push_str(&*self.temp);
-                self.content.push_str(&*character.to_string());
+                self.content.push_str(&*character.to_raw_string());
                 NonDeterministicTransitionEffect::transition_into::<ContentState>(self)
             }
         }
@@ -62,6 +66,7 @@ impl From<(LeadingWhitespaceState, Character)> for PotentiallyClosingState {
             level: state.level,
             content: "".into(),
             temp: character.to_string(),
+            options: state.options,
         }
     }
 }
@@ -72,6 +77,7 @@ impl From<PotentiallyTrailingState> for PotentiallyClosingState {
             level: value.level,
             content: value.content,
             temp: value.temp,
+            options: value.options,
         }
     }
 }
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::atx_heading::ATXHeadingSubState;
 use crate::parser::state::sub_state::atx_heading::closing::PotentiallyClosingState;
 use crate::parser::state::sub_state::atx_heading::leading::LeadingWhitespaceState;
@@ -21,6 +24,7 @@ use crate::unicode;
 pub struct ContentState {
     pub(crate) level: usize,
     pub(crate) content: String,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for ContentState {
@@ -35,7 +39,7 @@ impl Transition for ContentState {
                 ))
             }
             character => {
-                self.content.push_str(&*character.to_string());
+                self.content.push_str(&*character.to_raw_string());
                 NonDeterministicTransitionEffect::pass(self)
             }
         }
@@ -55,7 +59,8 @@ impl From<(LeadingWhitespaceState, Character)> for ContentState {
     fn from((state, character): (LeadingWhitespaceState, Character)) -> Self {
         Self {
             level: state.level,
-            content: character.to_string(),
+            content: character.to_raw_string(),
+            options: state.options,
         }
     }
 }
@@ -65,6 +70,7 @@ impl From<PotentiallyTrailingState> for ContentState {
         Self {
             level: value.level,
             content: value.content,
+            options: value.options,
         }
     }
 }
@@ -74,6 +80,7 @@ impl From<PotentiallyClosingState> for ContentState {
         Self {
             level: value.level,
             content: value.content,
+            options: value.options,
         }
     }
 }
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::atx_heading::{ATXHeadingSubState, ContentState, HASHTAG};
 use crate::parser::state::sub_state::atx_heading::closing::PotentiallyClosingState;
 use crate::parser::state::sub_state::atx_heading::opening::OpeningSequenceState;
@@ -20,6 +23,7 @@ use crate::unicode;
 /// * DocumentEnding
 pub struct LeadingWhitespaceState {
     pub(crate) level: usize,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for LeadingWhitespaceState {
@@ -65,6 +69,7 @@ impl From<OpeningSequenceState> for LeadingWhitespaceState {
     fn from(value: OpeningSequenceState) -> Self {
         Self {
             level: value.level,
+            options: value.options,
         }
     }
 }
\ No newline at end of file
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::atx_heading::{ATXHeadingSubState, HASHTAG, LeadingWhitespaceState};
 use crate::parser::transition::{Transition, TransitionEffect};
 use crate::unicode;
@@ -20,6 +23,7 @@ use crate::unicode;
 /// * Exceeding 6 [HASHTAG]
 pub struct OpeningSequenceState {
     pub(crate) level: usize,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for OpeningSequenceState {
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::atx_heading::closing::PotentiallyClosingState;
 use crate::parser::state::sub_state::atx_heading::content::ContentState;
 use crate::parser::state::sub_state::atx_heading::{ATXHeadingSubState, HASHTAG};
@@ -22,6 +25,7 @@ pub struct PotentiallyTrailingState {
     pub(crate) level: usize,
     pub(crate) content: String,
     pub(crate) temp: String,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for PotentiallyTrailingState {
@@ -46,7 +50,7 @@ impl Transition for PotentiallyTrailingState {
             },
             character => {
                 self.content.push_str(&*self.temp);
-                self.content.push_str(&*character.to_string());
+                self.content.push_str(&*character.to_raw_string());
                 NonDeterministicTransitionEffect::transition_into::<ContentState>(self)
             }
         }
@@ -68,6 +72,7 @@ impl From<(ContentState, Character)> for PotentiallyTrailingState {
             level: state.level,
             content: state.content,
             temp: character.to_string(),
+            options: state.options,
         }
     }
 }
@@ -78,6 +83,7 @@ impl From<PotentiallyClosingState> for PotentiallyTrailingState {
             level: value.level,
             content: value.content,
             temp: value.temp,
+            options: value.options,
         }
     }
 }
@@ -0,0 +1,86 @@
+//! Fences of at least 3 colons delimiting fenced admonitions and container directives
+
+pub const COLON: char = ':';
+
+/// Minimum number of [COLON] of a fence
+pub const MIN_FENCE_LENGTH: usize = 3;
+
+/// Rest of a line following a fence of at least 3 [COLON], indented by up to 3 spaces
+pub fn fence(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start_matches(' ');
+    let rest = trimmed.trim_start_matches(COLON);
+
+    let indented = line.len() - trimmed.len() < 4;
+    let fenced = trimmed.len() - rest.len() >= MIN_FENCE_LENGTH;
+
+    (indented && fenced).then_some(rest)
+}
+
+/// Whether a line consists of a fence only
+pub fn is_closing(line: &str) -> bool {
+    fence(line).is_some_and(|rest| rest.trim().is_empty())
+}
+
+/// Whether a line is a fence followed by text, opening a nested container
+pub fn is_opening(line: &str) -> bool {
+    fence(line).is_some_and(|rest| !rest.trim().is_empty())
+}
+
+/// Minimum number of backticks or tildes of a code fence
+const MIN_CODE_FENCE_LENGTH: usize = 3;
+
+/// # Fences
+/// Fences opened within the content of a fenced container
+///
+/// Colon fences within a backtick or tilde code fence are code, they neither open nor close a
+/// container.
+#[derive(Clone, Copy, Default)]
+pub struct Fences {
+    /// Nested containers opened
+    open: usize,
+    /// Character and length of the open code fence
+    code: Option<(char, usize)>,
+}
+
+impl Fences {
+    /// Whether a line is the closing fence of the container itself
+    pub fn is_closed_by(&self, line: &str) -> bool {
+        self.open == 0 && self.code.is_none() && is_closing(line)
+    }
+
+    /// Records the fences opened and closed by a line of the content
+    pub fn push(&mut self, line: &str) {
+        if let Some(code) = self.code {
+            if closes_code(line, code) {
+                self.code = None;
+            }
+        } else if let Some(code) = code_fence(line) {
+            self.code = Some(code);
+        } else if is_closing(line) {
+            self.open = self.open.saturating_sub(1);
+        } else if is_opening(line) {
+            self.open += 1;
+        }
+    }
+}
+
+/// Character and length of the backtick or tilde code fence opened by a line
+fn code_fence(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start_matches(' ');
+    let character = trimmed.chars().next().filter(|character| matches!(character, '`' | '~'))?;
+    let info = trimmed.trim_start_matches(character);
+    let length = trimmed.len() - info.len();
+
+    let indented = line.len() - trimmed.len() < 4;
+    let valid_info = character != '`' || !info.contains('`');
+
+    (indented && length >= MIN_CODE_FENCE_LENGTH && valid_info).then_some((character, length))
+}
+
+/// Whether a line closes the code fence of `character` opened with `length` characters
+fn closes_code(line: &str, (character, length): (char, usize)) -> bool {
+    let trimmed = line.trim_start_matches(' ');
+    let rest = trimmed.trim_start_matches(character);
+
+    line.len() - trimmed.len() < 4 && trimmed.len() - rest.len() >= length && rest.trim().is_empty()
+}
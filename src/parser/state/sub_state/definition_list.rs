@@ -0,0 +1,200 @@
+mod term;
+mod marker;
+mod description;
+mod newline;
+
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::handler::Handler;
+use crate::parser::state::sub_state::definition_list::description::DescriptionState;
+use crate::parser::state::sub_state::definition_list::marker::MarkerState;
+use crate::parser::state::sub_state::definition_list::newline::NewlineState;
+use crate::parser::state::sub_state::definition_list::term::TermState;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+const COLON: char = ':';
+
+pub type DefinitionListState = Handler<SubState, DefinitionListSubState>;
+
+impl TryFrom<(Character, Rc<ParseOptions>)> for DefinitionListState {
+    type Error = Error;
+
+    fn try_from((character, options): (Character, Rc<ParseOptions>)) -> Result<Self, Self::Error> {
+        Ok(DefinitionListState::new(TermState::new(character, options)?.into()))
+    }
+}
+
+impl From<DefinitionListState> for SubState {
+    fn from(value: DefinitionListState) -> Self {
+        value.state().into()
+    }
+}
+
+/// # Group
+/// Terms of a definition list followed by their definitions
+///
+/// Every definition holds its lines, an empty line separates its paragraphs. Consecutive groups
+/// form a single list once added to the document.
+#[derive(Default)]
+pub struct Group {
+    pub(crate) terms: Vec<String>,
+    pub(crate) definitions: Vec<Vec<String>>,
+    /// Whether a blank line precedes a definition or a paragraph of a definition
+    pub(crate) loose: bool,
+}
+
+impl Group {
+    fn block(self) -> Block {
+        let terms = self.terms.into_iter()
+            .map(|term| Container::new(ContainerKind::DefinitionTerm, vec![
+                Leaf::Paragraph {
+                    content: vec![Inline::Raw(term)],
+                }.into(),
+            ]).into());
+
+        let definitions = self.definitions.into_iter()
+            .map(|lines| {
+                let paragraphs = lines.split(String::is_empty)
+                    .filter(|paragraph| !paragraph.is_empty())
+                    .map(|paragraph| Leaf::Paragraph {
+                        content: vec![Inline::Raw(paragraph.join("\n"))],
+                    }.into())
+                    .collect();
+
+                Container::new(ContainerKind::DefinitionDescription, paragraphs).into()
+            });
+
+        Container::new(
+            ContainerKind::DefinitionList {
+                tight: !self.loose,
+            },
+            terms.chain(definitions).collect(),
+        ).into()
+    }
+}
+
+pub enum DefinitionListSubState {
+    Term(TermState),
+    Marker(MarkerState),
+    Description(DescriptionState),
+    NewLine(NewlineState),
+    Complete(State),
+}
+
+impl DefinitionListSubState {
+    /// A definition list claims the block once a definition is certain
+    pub fn claims(&self) -> bool {
+        match self {
+            DefinitionListSubState::Marker(state) => !state.group.definitions.is_empty(),
+            DefinitionListSubState::Description(_) => true,
+            DefinitionListSubState::NewLine(state) => !state.group.definitions.is_empty(),
+            _ => false,
+        }
+    }
+
+    pub fn complete(group: Group) -> NonDeterministicTransitionEffect<DefinitionListSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::complete::<Block>(group.block())
+    }
+}
+
+impl Transition for DefinitionListSubState {
+    type Effect = NonDeterministicTransitionEffect<DefinitionListSubState, Option<Block>>;
+
+    fn transition(self, character: Character) -> Self::Effect {
+        match self {
+            DefinitionListSubState::Term(state) => state.transition(character),
+            DefinitionListSubState::Marker(state) => state.transition(character),
+            DefinitionListSubState::Description(state) => state.transition(character),
+            DefinitionListSubState::NewLine(state) => state.transition(character),
+            DefinitionListSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        match self {
+            DefinitionListSubState::Term(state) => state.end_line(line_ending),
+            DefinitionListSubState::Marker(state) => state.end_line(line_ending),
+            DefinitionListSubState::Description(state) => state.end_line(line_ending),
+            DefinitionListSubState::NewLine(state) => state.end_line(line_ending),
+            DefinitionListSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self {
+            DefinitionListSubState::Term(state) => state.end(),
+            DefinitionListSubState::Marker(state) => state.end(),
+            DefinitionListSubState::Description(state) => state.end(),
+            DefinitionListSubState::NewLine(state) => state.end(),
+            DefinitionListSubState::Complete(_) => None,
+        }
+    }
+}
+
+impl From<DefinitionListSubState> for SubState {
+    fn from(value: DefinitionListSubState) -> Self {
+        match value {
+            DefinitionListSubState::Complete(state) => SubState::IntoSuper(state),
+            state => SubState::DefinitionList(
+                DefinitionListState::new(state)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Definition lists (extension)
+    fn test_definition_list() {
+        let parser = Parser::from_reader_with_options("Apple\n:   Pomaceous fruit\n    of plants.\n: Second *meaning*\n\nOrange\nCitrus\n: The fruit\n\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.definition_list = true));
+        assert_eq!(
+            "<dl>\n<dt>Apple</dt>\n<dd>Pomaceous fruit\nof plants.</dd>\n<dd>Second <em>meaning</em></dd>\n<dt>Orange</dt>\n<dt>Citrus</dt>\n<dd>The fruit</dd>\n</dl>\n<h1>After</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Definition lists (extension): definitions separated by blank lines
+    fn test_definition_list_paragraphs() {
+        let parser = Parser::from_reader_with_options("Term\n: First\n\n    Second paragraph\n\n: Other\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.definition_list = true));
+        assert_eq!(
+            "<dl>\n<dt>Term</dt>\n<dd>\n<p>First</p>\n<p>Second paragraph</p>\n</dd>\n<dd>\n<p>Other</p>\n</dd>\n</dl>\n<h1>After</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Definition lists (extension): a blank line between the term and its definition
+    fn test_definition_list_loose() {
+        let parser = Parser::from_reader_with_options("Term\n\n: def\n\n    more\n\nOther\n\n# After\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.definition_list = true));
+        assert_eq!(
+            "<dl>\n<dt>Term</dt>\n<dd>\n<p>def</p>\n<p>more</p>\n</dd>\n</dl>\n<h1>After</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Definition lists (extension): no term or disabled
+    fn test_definition_list_not_recognized() {
+        let parser = Parser::from_reader_with_options("# Term\n: definition\n".as_bytes(), ParseOptions::gfm().with_constructs(|constructs| constructs.definition_list = true));
+        assert_eq!("<h1>Term</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+
+        let parser = Parser::from_reader_with_options("Term\n: definition\n\n# After\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<h1>After</h1>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
@@ -0,0 +1,87 @@
+use std::mem;
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::definition_list::marker::MarkerState;
+use crate::parser::state::sub_state::definition_list::newline::NewlineState;
+use crate::parser::state::sub_state::definition_list::{DefinitionListSubState, Group};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Definition List: Description
+/// Line of the current definition, either following the marker or a continuation line
+///
+/// Leading whitespace of the line is skipped, the rest is kept raw for the inline pass.
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding
+///
+/// ## Completion
+/// * DocumentEnding
+pub struct DescriptionState {
+    pub(crate) group: Group,
+    pub(crate) options: Rc<ParseOptions>,
+    line: String,
+}
+
+impl DescriptionState {
+    fn push_line(&mut self) {
+        let line = mem::take(&mut self.line);
+
+        if let Some(definition) = self.group.definitions.last_mut() {
+            definition.push(line);
+        }
+    }
+}
+
+impl Transition for DescriptionState {
+    type Effect = NonDeterministicTransitionEffect<DefinitionListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if !(self.line.is_empty() && character.is_blank()) {
+            self.line.push_str(&character.to_raw_string());
+        }
+
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        self.push_line();
+        NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        self.push_line();
+        DefinitionListSubState::complete(self.group)
+            .end()
+    }
+}
+
+impl From<MarkerState> for DescriptionState {
+    fn from(value: MarkerState) -> Self {
+        Self {
+            group: value.group,
+            options: value.options,
+            line: String::new(),
+        }
+    }
+}
+
+impl From<(NewlineState, Character)> for DescriptionState {
+    fn from((state, character): (NewlineState, Character)) -> Self {
+        Self {
+            group: state.group,
+            options: state.options,
+            line: character.to_raw_string(),
+        }
+    }
+}
+
+impl From<DescriptionState> for DefinitionListSubState {
+    fn from(value: DescriptionState) -> Self {
+        DefinitionListSubState::Description(value)
+    }
+}
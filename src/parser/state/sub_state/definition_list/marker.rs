@@ -0,0 +1,87 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::definition_list::description::DescriptionState;
+use crate::parser::state::sub_state::definition_list::newline::NewlineState;
+use crate::parser::state::sub_state::definition_list::term::TermState;
+use crate::parser::state::sub_state::definition_list::{DefinitionListSubState, Group, COLON};
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Definition List: Marker
+/// [COLON] at the start of a line following a term or a definition
+///
+/// ## Transition
+/// * [DescriptionState] on whitespace character
+/// * [NewlineState] on LineEnding, with an empty definition
+/// * [TermState] on non-whitespace character following a term
+///
+/// ## Completion
+/// * non-whitespace character following a definition, the line is handed back
+/// * DocumentEnding, with an empty definition
+pub struct MarkerState {
+    pub(crate) group: Group,
+    pub(crate) options: Rc<ParseOptions>,
+    leading_spaces: usize,
+}
+
+impl Transition for MarkerState {
+    type Effect = NonDeterministicTransitionEffect<DefinitionListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if character.is_blank() {
+            self.group.definitions.push(vec![]);
+            return NonDeterministicTransitionEffect::transition_into::<DescriptionState>(self);
+        }
+
+        if self.group.definitions.is_empty() {
+            let line = format!("{COLON}{}", character.to_raw_string());
+            return NonDeterministicTransitionEffect::transition_into::<TermState>((
+                NewlineState::from(self),
+                line,
+            ));
+        }
+
+        let (state, _) = State::from_leading_space_count(self.leading_spaces, self.options)
+            .transition(Character::Unescaped(COLON))
+            .content();
+        let (state, _) = state.transition(character)
+            .content();
+
+        NonDeterministicTransitionEffect::new(
+            DefinitionListSubState::Complete(state),
+            Some(self.group.block()),
+        )
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        self.group.definitions.push(vec![]);
+        NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        self.group.definitions.push(vec![]);
+        DefinitionListSubState::complete(self.group)
+            .end()
+    }
+}
+
+impl From<NewlineState> for MarkerState {
+    fn from(value: NewlineState) -> Self {
+        Self {
+            leading_spaces: value.leading_spaces,
+            group: value.group,
+            options: value.options,
+        }
+    }
+}
+
+impl From<MarkerState> for DefinitionListSubState {
+    fn from(value: MarkerState) -> Self {
+        DefinitionListSubState::Marker(value)
+    }
+}
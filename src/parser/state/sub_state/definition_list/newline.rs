@@ -0,0 +1,158 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::definition_list::description::DescriptionState;
+use crate::parser::state::sub_state::definition_list::marker::MarkerState;
+use crate::parser::state::sub_state::definition_list::term::TermState;
+use crate::parser::state::sub_state::definition_list::{DefinitionListSubState, Group, COLON};
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// Indentation of a line continuing a definition after a blank line
+const PARAGRAPH_INDENTATION: usize = 4;
+
+/// # Definition List: Newline
+/// Start of a line following a term or a definition
+///
+/// Following a definition, a line indented by at least one space continues its paragraph and,
+/// after blank lines, a line indented by an equivalent of 4 spaces starts a further paragraph. A
+/// single blank line may separate the terms from their first definition, making the list loose.
+///
+/// ## Transition
+/// * [MarkerState] on [COLON] before an equivalent of 4 spaces
+/// * [TermState] on any other character directly following a term
+/// * [DescriptionState] on a continuation line of a definition
+///
+/// ## Completion
+/// * any other character following a definition, the line is handed back
+/// * DocumentEnding following a definition
+///
+/// ## Dismissal
+/// * any other character following a term and a blank line, the line is handed back
+/// * LineEnding of a second blank line following a term
+/// * DocumentEnding following a term
+pub struct NewlineState {
+    pub(crate) group: Group,
+    pub(crate) options: Rc<ParseOptions>,
+    pub(crate) leading_spaces: usize,
+    blank: bool,
+}
+
+impl NewlineState {
+    fn continues_definition(&self) -> bool {
+        match self.blank {
+            true => self.leading_spaces >= PARAGRAPH_INDENTATION,
+            false => self.leading_spaces > 0,
+        }
+    }
+}
+
+impl Transition for NewlineState {
+    type Effect = NonDeterministicTransitionEffect<DefinitionListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.leading_spaces += character.space_count();
+
+        if character.is_blank() {
+            return NonDeterministicTransitionEffect::pass(self);
+        }
+
+        if let Character::Unescaped(COLON) = character {
+            if self.leading_spaces < PARAGRAPH_INDENTATION {
+                self.group.loose |= self.blank;
+                return NonDeterministicTransitionEffect::transition_into::<MarkerState>(self);
+            }
+        }
+
+        if let (true, false) = (self.group.definitions.is_empty(), self.blank) {
+            let line = character.to_raw_string();
+            return NonDeterministicTransitionEffect::transition_into::<TermState>((self, line));
+        }
+
+        if self.continues_definition() {
+            if self.blank {
+                self.group.loose = true;
+                if let Some(definition) = self.group.definitions.last_mut() {
+                    definition.push(String::new());
+                }
+            }
+
+            return NonDeterministicTransitionEffect::transition_into::<DescriptionState>((self, character));
+        }
+
+        let (state, _) = State::from_leading_space_count(self.leading_spaces, self.options)
+            .transition(character)
+            .content();
+
+        let block = match self.group.definitions.is_empty() {
+            true => None,
+            false => Some(self.group.block()),
+        };
+
+        NonDeterministicTransitionEffect::new(
+            DefinitionListSubState::Complete(state),
+            block,
+        )
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        if let (true, true) = (self.group.definitions.is_empty(), self.blank) {
+            return NonDeterministicTransitionEffect::dismiss();
+        }
+
+        self.blank = true;
+        self.leading_spaces = 0;
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self.group.definitions.is_empty() {
+            true => None,
+            false => DefinitionListSubState::complete(self.group)
+                .end(),
+        }
+    }
+}
+
+impl From<TermState> for NewlineState {
+    fn from(value: TermState) -> Self {
+        Self {
+            group: value.group,
+            options: value.options,
+            leading_spaces: 0,
+            blank: false,
+        }
+    }
+}
+
+impl From<MarkerState> for NewlineState {
+    fn from(value: MarkerState) -> Self {
+        Self {
+            group: value.group,
+            options: value.options,
+            leading_spaces: 0,
+            blank: false,
+        }
+    }
+}
+
+impl From<DescriptionState> for NewlineState {
+    fn from(value: DescriptionState) -> Self {
+        Self {
+            group: value.group,
+            options: value.options,
+            leading_spaces: 0,
+            blank: false,
+        }
+    }
+}
+
+impl From<NewlineState> for DefinitionListSubState {
+    fn from(value: NewlineState) -> Self {
+        DefinitionListSubState::NewLine(value)
+    }
+}
@@ -0,0 +1,73 @@
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::definition_list::newline::NewlineState;
+use crate::parser::state::sub_state::definition_list::{DefinitionListSubState, Group, COLON};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Definition List: Term
+/// Line of a potential term, kept raw for the inline pass
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding
+///
+/// ## Dismissal
+/// * DocumentEnding
+pub struct TermState {
+    pub(crate) group: Group,
+    pub(crate) options: Rc<ParseOptions>,
+    line: String,
+}
+
+impl TermState {
+    /// A line starting with a [COLON] is a definition marker rather than a term
+    pub fn new(character: Character, options: Rc<ParseOptions>) -> Result<Self, Error> {
+        match character {
+            Character::Unescaped(COLON) => Err(Error::StartState),
+            character => Ok(Self {
+                group: Group::default(),
+                options,
+                line: character.to_raw_string(),
+            }),
+        }
+    }
+}
+
+impl Transition for TermState {
+    type Effect = NonDeterministicTransitionEffect<DefinitionListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push_str(&character.to_raw_string());
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        self.group.terms.push(self.line.trim_end().to_string());
+        NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        None
+    }
+}
+
+impl From<(NewlineState, String)> for TermState {
+    fn from((state, line): (NewlineState, String)) -> Self {
+        Self {
+            group: state.group,
+            options: state.options,
+            line,
+        }
+    }
+}
+
+impl From<TermState> for DefinitionListSubState {
+    fn from(value: TermState) -> Self {
+        DefinitionListSubState::Term(value)
+    }
+}
@@ -0,0 +1,115 @@
+mod opening;
+mod content;
+
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::character_parser;
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::document::directive::Directive;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::handler::Handler;
+use crate::parser::state::sub_state::colon_fence::COLON;
+use crate::parser::state::sub_state::directive::content::ContentState;
+use crate::parser::state::sub_state::directive::opening::OpeningState;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+pub type DirectiveState = Handler<SubState, DirectiveSubState>;
+
+impl TryFrom<(Character, Rc<ParseOptions>)> for DirectiveState {
+    type Error = Error;
+
+    fn try_from((character, options): (Character, Rc<ParseOptions>)) -> Result<Self, Self::Error> {
+        match character {
+            Character::Unescaped(COLON) => Ok(DirectiveState::new(OpeningState::new(options).into())),
+            _ => Err(Error::StartState),
+        }
+    }
+}
+
+impl From<DirectiveState> for SubState {
+    fn from(value: DirectiveState) -> Self {
+        value.state().into()
+    }
+}
+
+/// # Body
+/// Directive and raw lines of a container directive, its lines are parsed as blocks on completion
+pub struct Body {
+    pub(crate) directive: Directive,
+    pub(crate) lines: Vec<String>,
+}
+
+impl Body {
+    pub fn new(directive: Directive) -> Self {
+        Self {
+            directive,
+            lines: vec![],
+        }
+    }
+
+    /// Container directive, nested containers count against the nesting depth limit
+    fn block(self, options: &ParseOptions) -> Block {
+        let mut options = options.clone();
+        options.limits.max_nesting_depth = options.limits.max_nesting_depth.saturating_sub(1);
+
+        Container::new(
+            ContainerKind::Directive(self.directive),
+            character_parser::parse_blocks(&self.lines.join("\n"), options),
+        ).into()
+    }
+}
+
+pub enum DirectiveSubState {
+    Opening(OpeningState),
+    Content(ContentState),
+}
+
+impl DirectiveSubState {
+    /// A container directive claims the block once its opening fence is complete
+    pub fn claims(&self) -> bool {
+        matches!(self, DirectiveSubState::Content(_))
+    }
+
+    pub fn complete(body: Body, options: &ParseOptions) -> NonDeterministicTransitionEffect<DirectiveSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::complete::<Block>(body.block(options))
+    }
+}
+
+impl Transition for DirectiveSubState {
+    type Effect = NonDeterministicTransitionEffect<DirectiveSubState, Option<Block>>;
+
+    fn transition(self, character: Character) -> Self::Effect {
+        match self {
+            DirectiveSubState::Opening(state) => state.transition(character),
+            DirectiveSubState::Content(state) => state.transition(character),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        match self {
+            DirectiveSubState::Opening(state) => state.end_line(line_ending),
+            DirectiveSubState::Content(state) => state.end_line(line_ending),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self {
+            DirectiveSubState::Opening(state) => state.end(),
+            DirectiveSubState::Content(state) => state.end(),
+        }
+    }
+}
+
+impl From<DirectiveSubState> for SubState {
+    fn from(value: DirectiveSubState) -> Self {
+        SubState::Directive(
+            DirectiveState::new(value)
+        )
+    }
+}
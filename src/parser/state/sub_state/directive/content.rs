@@ -0,0 +1,74 @@
+use std::mem;
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::colon_fence::Fences;
+use crate::parser::state::sub_state::directive::{Body, DirectiveSubState};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Directive: Content
+/// Line of a container directive, kept raw to be parsed as blocks
+///
+/// Every fence opening a nested container has to be closed before a closing fence ends the
+/// directive, fences within a code fence are ignored.
+///
+/// ## Completion
+/// * LineEnding of the closing fence
+/// * DocumentEnding
+pub struct ContentState {
+    body: Body,
+    options: Rc<ParseOptions>,
+    fences: Fences,
+    line: String,
+}
+
+impl Transition for ContentState {
+    type Effect = NonDeterministicTransitionEffect<DirectiveSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push_str(&character.to_raw_string());
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        let line = mem::take(&mut self.line);
+
+        if self.fences.is_closed_by(&line) {
+            return DirectiveSubState::complete(self.body, &self.options);
+        }
+
+        self.fences.push(&line);
+        self.body.lines.push(line);
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        if !self.fences.is_closed_by(&self.line) {
+            self.body.lines.push(self.line);
+        }
+
+        DirectiveSubState::complete(self.body, &self.options)
+            .end()
+    }
+}
+
+impl From<(Body, Rc<ParseOptions>)> for ContentState {
+    fn from((body, options): (Body, Rc<ParseOptions>)) -> Self {
+        Self {
+            body,
+            options,
+            fences: Fences::default(),
+            line: String::new(),
+        }
+    }
+}
+
+impl From<ContentState> for DirectiveSubState {
+    fn from(value: ContentState) -> Self {
+        DirectiveSubState::Content(value)
+    }
+}
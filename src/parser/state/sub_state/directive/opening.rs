@@ -0,0 +1,103 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::directive;
+use crate::parser::document::block::Block;
+use crate::parser::document::directive::{Directive, DirectiveKind};
+use crate::parser::document::leaf::Leaf;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::colon_fence::{COLON, MIN_FENCE_LENGTH};
+use crate::parser::state::sub_state::directive::content::ContentState;
+use crate::parser::state::sub_state::directive::{Body, DirectiveSubState};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// Number of [COLON] of a leaf directive
+const LEAF_LENGTH: usize = 2;
+
+/// # Directive: Opening
+/// `::name[label]{attributes}` leaf directive or `:::name[label]{attributes}` line opening a
+/// container directive, followed by optional whitespace
+///
+/// ## Transition
+/// * [ContentState] on LineEnding following a container directive
+///
+/// ## Completion
+/// * LineEnding or DocumentEnding following a leaf directive
+///
+/// ## Dismissal
+/// * any character other than [COLON] as second character
+/// * LineEnding or DocumentEnding following anything else
+/// * LineEnding following a container directive once the nesting depth limit is reached
+pub struct OpeningState {
+    pub(crate) options: Rc<ParseOptions>,
+    line: Vec<char>,
+}
+
+impl OpeningState {
+    pub fn new(options: Rc<ParseOptions>) -> Self {
+        Self {
+            options,
+            line: vec![COLON],
+        }
+    }
+
+    /// Directive of the line if nothing but whitespace follows it
+    fn directive(&self) -> Option<Directive> {
+        let colons = self.line.iter()
+            .take_while(|character| **character == COLON)
+            .count();
+
+        let kind = match colons {
+            LEAF_LENGTH => DirectiveKind::Leaf,
+            colons if colons >= MIN_FENCE_LENGTH => DirectiveKind::Container,
+            _ => return None,
+        };
+
+        let (directive, end) = directive::parse(kind, &self.line, colons, self.options.limits.max_link_label_length)?;
+
+        self.line[end..].iter()
+            .all(|character| character.is_whitespace())
+            .then_some(directive)
+    }
+}
+
+impl Transition for OpeningState {
+    type Effect = NonDeterministicTransitionEffect<DirectiveSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if self.line.len() < LEAF_LENGTH && !matches!(character, Character::Unescaped(COLON)) {
+            return NonDeterministicTransitionEffect::dismiss();
+        }
+
+        self.line.extend(character.to_raw_string().chars());
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        match self.directive() {
+            Some(directive) if directive.kind == DirectiveKind::Leaf => {
+                NonDeterministicTransitionEffect::complete::<Block>(Leaf::Directive(directive))
+            }
+            Some(directive) if self.options.limits.max_nesting_depth > 0 => {
+                NonDeterministicTransitionEffect::transition_into::<ContentState>((Body::new(directive), self.options))
+            }
+            _ => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self.directive() {
+            Some(directive) if directive.kind == DirectiveKind::Leaf => Some(Leaf::Directive(directive).into()),
+            Some(directive) if self.options.limits.max_nesting_depth > 0 => Some(Body::new(directive).block(&self.options)),
+            _ => None,
+        }
+    }
+}
+
+impl From<OpeningState> for DirectiveSubState {
+    fn from(value: OpeningState) -> Self {
+        DirectiveSubState::Opening(value)
+    }
+}
@@ -5,10 +5,15 @@ mod newline;
 mod content;
 mod closing;
 
+use std::rc::Rc;
+
 use crate::error::Error;
 use crate::parser::character::Character;
+use crate::parser::document::attributes::Attributes;
 use crate::parser::document::block::Block;
+use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::handler::Handler;
 use crate::parser::state::sub_state::fenced_code_block::closing::ClosingState;
 use crate::parser::state::sub_state::fenced_code_block::content::ContentState;
@@ -22,6 +27,7 @@ use crate::parser::transition::{Transition, TransitionEffect};
 
 pub(self) const BACKTICK: char = '`';
 pub(self) const TILDE: char = '~';
+const MATH_LANGUAGE: &str = "math";
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum FenceCharacter {
@@ -56,13 +62,13 @@ impl TryFrom<&Character> for FenceCharacter {
 
 pub type FencedCodeBlockState = Handler<SubState, FencedCodeBlockSubState>;
 
-impl TryFrom<(usize, Character)> for FencedCodeBlockState {
+impl TryFrom<(usize, Character, Rc<ParseOptions>)> for FencedCodeBlockState {
     type Error = Error;
 
-    fn try_from((indentation, character): (usize, Character)) -> Result<Self, Error> {
+    fn try_from((indentation, character, options): (usize, Character, Rc<ParseOptions>)) -> Result<Self, Error> {
         Ok(
             FencedCodeBlockState::new(
-                OpeningState::new(indentation, character)?
+                OpeningState::new(indentation, character, options)?
                     .into()
             )
         )
@@ -75,6 +81,30 @@ impl From<FencedCodeBlockState> for SubState {
     }
 }
 
+/// Completed block, dispatched on the first word of the info string
+///
+/// A `math` info string yields a [Leaf::MathBlock] when math is enabled, any other a
+/// [Leaf::FencedCodeBlock]. With attributes enabled, a trailing attribute block is split off the
+/// info string, an info string consisting of the block only leaves the code block without
+/// language.
+pub fn leaf(text: String, info: Option<String>, options: &ParseOptions) -> Leaf {
+    let (info, attributes) = match info {
+        Some(info) if options.constructs.attributes => match Attributes::split_trailing(&info) {
+            Some((rest, attributes)) => (Some(rest.to_string()).filter(|rest| !rest.is_empty()), attributes),
+            None => (Some(info), Attributes::default()),
+        },
+        info => (info, Attributes::default()),
+    };
+
+    let language = info.as_deref()
+        .and_then(|info| info.split_whitespace().next());
+
+    match language {
+        Some(MATH_LANGUAGE) if options.constructs.math_flow => Leaf::MathBlock { text },
+        _ => Leaf::FencedCodeBlock { text, info, attributes },
+    }
+}
+
 pub enum FencedCodeBlockSubState {
     Opening(OpeningState),
     Info(InfoStringState),
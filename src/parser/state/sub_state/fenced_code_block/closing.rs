@@ -1,8 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
-use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::fenced_code_block;
 use crate::parser::state::sub_state::fenced_code_block::{FenceCharacter, FencedCodeBlockSubState};
 use crate::parser::state::sub_state::fenced_code_block::content::ContentState;
@@ -24,6 +26,7 @@ pub struct ClosingState {
     pub(crate) character: FenceCharacter,
     pub(crate) character_count: usize,
     pub(crate) indentation: usize,
+    pub(crate) options: Rc<ParseOptions>,
     pub(crate) info_string: Option<String>,
     pub(crate) content: String,
     leading_spaces: usize,
@@ -79,10 +82,7 @@ impl Transition for ClosingState {
 
     fn end_line(mut self, line_ending: LineEnding) -> Self::Effect {
         if self.closing_fence_length >= self.character_count {
-            NonDeterministicTransitionEffect::complete::<Block>(Leaf::FencedCodeBlock {
-                text: self.content,
-                info: self.info_string,
-            })
+            NonDeterministicTransitionEffect::complete::<Block>(fenced_code_block::leaf(self.content, self.info_string, &self.options))
         } else {
             self.finish(line_ending.to_string());
             NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
@@ -90,10 +90,7 @@ impl Transition for ClosingState {
     }
 
     fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
-        Some(Leaf::FencedCodeBlock {
-            text: self.content,
-            info: self.info_string,
-        }.into())
+        Some(fenced_code_block::leaf(self.content, self.info_string, &self.options).into())
     }
 }
 
@@ -103,6 +100,7 @@ impl From<NewlineState> for ClosingState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
+            options: value.options,
             info_string: value.info_string,
             content: value.content,
             leading_spaces: value.leading_spaces,
@@ -1,8 +1,11 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
-use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::fenced_code_block;
 use crate::parser::state::sub_state::fenced_code_block::newline::NewlineState;
 use crate::parser::state::sub_state::fenced_code_block::{FenceCharacter, FencedCodeBlockSubState};
 use crate::parser::state::sub_state::fenced_code_block::closing::ClosingState;
@@ -20,6 +23,7 @@ pub struct ContentState {
     pub(crate) character: FenceCharacter,
     pub(crate) character_count: usize,
     pub(crate) indentation: usize,
+    pub(crate) options: Rc<ParseOptions>,
     pub(crate) info_string: Option<String>,
     pub(crate) content: String,
 }
@@ -38,10 +42,7 @@ impl Transition for ContentState {
     }
 
     fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
-        Some(Leaf::FencedCodeBlock {
-            text: self.content,
-            info: self.info_string,
-        }.into())
+        Some(fenced_code_block::leaf(self.content, self.info_string, &self.options).into())
     }
 }
 
@@ -51,6 +52,7 @@ impl From<NewlineState> for ContentState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
+            options: value.options,
             info_string: value.info_string,
             content: value.content,
         }
@@ -63,6 +65,7 @@ impl From<ClosingState> for ContentState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
+            options: value.options,
             info_string: value.info_string,
             content: value.content,
         }
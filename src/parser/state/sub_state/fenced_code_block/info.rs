@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::fenced_code_block;
 use crate::parser::state::sub_state::fenced_code_block::{FenceCharacter, FencedCodeBlockSubState};
 use crate::parser::state::sub_state::fenced_code_block::newline::NewlineState;
@@ -24,6 +27,7 @@ pub struct InfoStringState {
     pub(crate) character: FenceCharacter,
     pub(crate) character_count: usize,
     pub(crate) indentation: usize,
+    pub(crate) options: Rc<ParseOptions>,
     pub(crate) info_string: String,
 }
 
@@ -32,11 +36,11 @@ impl Transition for InfoStringState {
 
     fn transition(mut self, character: Character) -> Self::Effect {
         match character {
-            Character::Unescaped(unicode::SPACE | unicode::TAB) => {
+            character @ Character::Unescaped(unicode::SPACE | unicode::TAB) => {
                 if self.info_string.is_empty() {
                     NonDeterministicTransitionEffect::pass(self)
                 } else {
-                    NonDeterministicTransitionEffect::transition_into::<OpeningTrailingState>(self)
+                    NonDeterministicTransitionEffect::transition_into::<OpeningTrailingState>((self, character))
                 }
             }
             Character::Unescaped(fenced_code_block::BACKTICK) => {
@@ -72,6 +76,7 @@ impl From<(OpeningState, Character)> for InfoStringState {
             character: state.character,
             character_count: state.character_count,
             indentation: state.indentation,
+            options: state.options,
             info_string: character.to_string().trim().to_string(),
         }
     }
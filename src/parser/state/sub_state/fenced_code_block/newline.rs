@@ -1,8 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
-use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::fenced_code_block;
 use crate::parser::state::sub_state::fenced_code_block::{FenceCharacter, FencedCodeBlockSubState};
 use crate::parser::state::sub_state::fenced_code_block::closing::ClosingState;
@@ -27,6 +29,7 @@ pub struct NewlineState {
     pub(crate) character: FenceCharacter,
     pub(crate) character_count: usize,
     pub(crate) indentation: usize,
+    pub(crate) options: Rc<ParseOptions>,
     pub(crate) info_string: Option<String>,
     pub(crate) content: String,
     pub(crate) leading_spaces: usize,
@@ -77,10 +80,7 @@ impl Transition for NewlineState {
     }
 
     fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
-        Some(Leaf::FencedCodeBlock {
-            text: self.content,
-            info: self.info_string,
-        }.into())
+        Some(fenced_code_block::leaf(self.content, self.info_string, &self.options).into())
     }
 }
 
@@ -90,6 +90,7 @@ impl From<OpeningState> for NewlineState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
+            options: value.options,
             info_string: None,
             content: "".to_string(),
             leading_spaces: 0,
@@ -103,6 +104,7 @@ impl From<InfoStringState> for NewlineState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
+            options: value.options,
             info_string: Some(value.info_string),
             content: "".to_string(),
             leading_spaces: 0,
@@ -116,7 +118,8 @@ impl From<OpeningTrailingState> for NewlineState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
-            info_string: Some(value.info_string),
+            options: value.options,
+            info_string: Some(value.info_string.trim_end().to_string()),
             content: "".to_string(),
             leading_spaces: 0,
         }
@@ -129,6 +132,7 @@ impl From<ContentState> for NewlineState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
+            options: value.options,
             info_string: value.info_string,
             content: value.content,
             leading_spaces: 0,
@@ -142,6 +146,7 @@ impl From<ClosingState> for NewlineState {
             character: value.character,
             character_count: value.character_count,
             indentation: value.indentation,
+            options: value.options,
             info_string: value.info_string,
             content: value.content,
             leading_spaces: 0,
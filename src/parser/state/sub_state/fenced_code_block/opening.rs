@@ -1,38 +1,42 @@
+use std::rc::Rc;
+
 use crate::error::Error;
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::fenced_code_block;
 use crate::parser::state::sub_state::fenced_code_block::{FenceCharacter, FencedCodeBlockSubState};
 use crate::parser::state::sub_state::fenced_code_block::info::InfoStringState;
 use crate::parser::state::sub_state::fenced_code_block::newline::NewlineState;
 use crate::parser::transition::{Transition, TransitionEffect};
-use crate::unicode;
 
 /// # Fenced Code Block: Opening Fence
 /// Opening sequence of up to N [FenceCharacter]s
 ///
 /// ## Transition
-/// * [InfoStringState] on non-whitespace character
-/// * [NewlineState] on LineEnding
+/// * [InfoStringState] on any other character after at least three [FenceCharacter]s
+/// * [NewlineState] on LineEnding after at least three [FenceCharacter]s
 ///
 /// ## Dismissal
 /// * DocumentEnding
-/// * on [BACKTICK] when [FenceCharacter] is [BACKTICK]
+/// * on any other character or LineEnding after less than three [FenceCharacter]s
 pub struct OpeningState {
     pub(crate) character: FenceCharacter,
     pub(crate) character_count: usize,
     pub(crate) indentation: usize,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl OpeningState {
-    pub fn new(indentation: usize, character: Character) -> Result<Self, Error> {
+    pub fn new(indentation: usize, character: Character, options: Rc<ParseOptions>) -> Result<Self, Error> {
         Ok(
             Self {
                 character: FenceCharacter::try_from(&character)?,
                 character_count: 1,
                 indentation,
+                options,
             }
         )
     }
@@ -42,46 +46,14 @@ impl Transition for OpeningState {
     type Effect = NonDeterministicTransitionEffect<FencedCodeBlockSubState, Option<Block>>;
 
     fn transition(mut self, character: Character) -> Self::Effect {
-        match character {
-            Character::Unescaped(unicode::SPACE | unicode::TAB) => {
-                if self.character_count >= 3 {
-                    NonDeterministicTransitionEffect::transition_into::<InfoStringState>((
-                        self,
-                        character
-                    ))
-                } else {
-                    NonDeterministicTransitionEffect::dismiss()
-                }
-            }
-            character @ Character::Unescaped(fenced_code_block::BACKTICK) => {
-                match self.character {
-                    FenceCharacter::Backtick => {
-                        self.character_count += 1;
-                        NonDeterministicTransitionEffect::pass(self)
-                    }
-                    FenceCharacter::Tilde => {
-                        NonDeterministicTransitionEffect::transition_into::<InfoStringState>((
-                            self,
-                            character,
-                        ))
-                    }
-                }
-            }
-            character @ Character::Unescaped(fenced_code_block::TILDE) => {
-                match self.character {
-                    FenceCharacter::Tilde => {
-                        self.character_count += 1;
-                        NonDeterministicTransitionEffect::pass(self)
-                    }
-                    FenceCharacter::Backtick => {
-                        NonDeterministicTransitionEffect::transition_into::<InfoStringState>((
-                            self,
-                            character,
-                        ))
-                    }
-                }
+        match (&character, self.character) {
+            (Character::Unescaped(fenced_code_block::BACKTICK), FenceCharacter::Backtick) |
+            (Character::Unescaped(fenced_code_block::TILDE), FenceCharacter::Tilde) => {
+                self.character_count += 1;
+                NonDeterministicTransitionEffect::pass(self)
             }
-            character => {
+            _ if self.character_count < 3 => NonDeterministicTransitionEffect::dismiss(),
+            _ => {
                 NonDeterministicTransitionEffect::transition_into::<InfoStringState>((
                     self,
                     character,
@@ -91,6 +63,10 @@ impl Transition for OpeningState {
     }
 
     fn end_line(self, _: LineEnding) -> Self::Effect {
+        if self.character_count < 3 {
+            return NonDeterministicTransitionEffect::dismiss();
+        }
+
         NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
     }
 
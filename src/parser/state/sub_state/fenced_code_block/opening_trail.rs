@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::fenced_code_block;
 use crate::parser::state::sub_state::fenced_code_block::{FenceCharacter, FencedCodeBlockSubState};
 use crate::parser::state::sub_state::fenced_code_block::info::InfoStringState;
@@ -9,7 +12,7 @@ use crate::parser::state::sub_state::fenced_code_block::newline::NewlineState;
 use crate::parser::transition::{Transition, TransitionEffect};
 
 /// # Fenced Code Block: Opening fence trail
-/// Opening fence trail
+/// Words of the info string following the first one
 ///
 /// ## Transition
 /// * [NewlineState] on LineEnding
@@ -21,13 +24,14 @@ pub struct OpeningTrailingState {
     pub(crate) character: FenceCharacter,
     pub(crate) character_count: usize,
     pub(crate) indentation: usize,
+    pub(crate) options: Rc<ParseOptions>,
     pub(crate) info_string: String,
 }
 
 impl Transition for OpeningTrailingState {
     type Effect = NonDeterministicTransitionEffect<FencedCodeBlockSubState, Option<Block>>;
 
-    fn transition(self, character: Character) -> Self::Effect {
+    fn transition(mut self, character: Character) -> Self::Effect {
         match character {
             Character::Unescaped(fenced_code_block::BACKTICK) => {
                 match self.character {
@@ -35,11 +39,13 @@ impl Transition for OpeningTrailingState {
                         NonDeterministicTransitionEffect::dismiss()
                     }
                     FenceCharacter::Tilde => {
+                        self.info_string.push_str(&character.to_string());
                         NonDeterministicTransitionEffect::pass(self)
                     }
                 }
             }
-            _ => {
+            character => {
+                self.info_string.push_str(&character.to_string());
                 NonDeterministicTransitionEffect::pass(self)
             }
         }
@@ -54,13 +60,14 @@ impl Transition for OpeningTrailingState {
     }
 }
 
-impl From<InfoStringState> for OpeningTrailingState {
-    fn from(value: InfoStringState) -> Self {
+impl From<(InfoStringState, Character)> for OpeningTrailingState {
+    fn from((state, character): (InfoStringState, Character)) -> Self {
         Self {
-            character: value.character,
-            character_count: value.character_count,
-            indentation: value.indentation,
-            info_string: value.info_string,
+            character: state.character,
+            character_count: state.character_count,
+            indentation: state.indentation,
+            options: state.options,
+            info_string: state.info_string + &character.to_string(),
         }
     }
 }
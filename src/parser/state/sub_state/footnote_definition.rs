@@ -0,0 +1,123 @@
+mod label;
+mod content;
+mod newline;
+mod lazy;
+
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind};
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::handler::Handler;
+use crate::parser::state::sub_state::footnote_definition::content::ContentState;
+use crate::parser::state::sub_state::footnote_definition::label::LabelState;
+use crate::parser::state::sub_state::footnote_definition::lazy::LazyState;
+use crate::parser::state::sub_state::footnote_definition::newline::NewlineState;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+pub type FootnoteDefinitionState = Handler<SubState, FootnoteDefinitionSubState>;
+
+impl TryFrom<(Character, Rc<ParseOptions>)> for FootnoteDefinitionState {
+    type Error = Error;
+
+    fn try_from((character, options): (Character, Rc<ParseOptions>)) -> Result<Self, Self::Error> {
+        Ok(FootnoteDefinitionState::new(LabelState::new(character, options)?.into()))
+    }
+}
+
+impl From<FootnoteDefinitionState> for SubState {
+    fn from(value: FootnoteDefinitionState) -> Self {
+        value.state().into()
+    }
+}
+
+pub enum FootnoteDefinitionSubState {
+    Label(LabelState),
+    Content(ContentState),
+    NewLine(NewlineState),
+    Lazy(LazyState),
+    Complete(State),
+}
+
+impl FootnoteDefinitionSubState {
+    /// A footnote definition claims the block once its `[^label]:` marker is complete
+    pub fn claims(&self) -> bool {
+        matches!(self, FootnoteDefinitionSubState::Content(_) | FootnoteDefinitionSubState::NewLine(_) | FootnoteDefinitionSubState::Lazy(_))
+    }
+
+    pub fn complete(label: String, lines: Vec<String>) -> NonDeterministicTransitionEffect<FootnoteDefinitionSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::complete::<Block>(Self::definition(label, lines))
+    }
+
+    /// Definition container holding one raw paragraph per group of lines separated by blank lines
+    fn definition(label: String, lines: Vec<String>) -> Block {
+        let paragraphs = lines.split(String::is_empty)
+            .filter(|paragraph| !paragraph.is_empty())
+            .map(|paragraph| Leaf::Paragraph {
+                content: vec![Inline::Raw(paragraph.join("\n"))],
+            }.into())
+            .collect();
+
+        Container::new(
+            ContainerKind::FootnoteDefinition {
+                label,
+                index: 0,
+                references: 0,
+            },
+            paragraphs,
+        ).into()
+    }
+}
+
+impl Transition for FootnoteDefinitionSubState {
+    type Effect = NonDeterministicTransitionEffect<FootnoteDefinitionSubState, Option<Block>>;
+
+    fn transition(self, character: Character) -> Self::Effect {
+        match self {
+            FootnoteDefinitionSubState::Label(state) => state.transition(character),
+            FootnoteDefinitionSubState::Content(state) => state.transition(character),
+            FootnoteDefinitionSubState::NewLine(state) => state.transition(character),
+            FootnoteDefinitionSubState::Lazy(state) => state.transition(character),
+            FootnoteDefinitionSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        match self {
+            FootnoteDefinitionSubState::Label(state) => state.end_line(line_ending),
+            FootnoteDefinitionSubState::Content(state) => state.end_line(line_ending),
+            FootnoteDefinitionSubState::NewLine(state) => state.end_line(line_ending),
+            FootnoteDefinitionSubState::Lazy(state) => state.end_line(line_ending),
+            FootnoteDefinitionSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self {
+            FootnoteDefinitionSubState::Label(state) => state.end(),
+            FootnoteDefinitionSubState::Content(state) => state.end(),
+            FootnoteDefinitionSubState::NewLine(state) => state.end(),
+            FootnoteDefinitionSubState::Lazy(state) => state.end(),
+            FootnoteDefinitionSubState::Complete(_) => None,
+        }
+    }
+}
+
+impl From<FootnoteDefinitionSubState> for SubState {
+    fn from(value: FootnoteDefinitionSubState) -> Self {
+        match value {
+            FootnoteDefinitionSubState::Complete(state) => SubState::IntoSuper(state),
+            state => SubState::FootnoteDefinition(
+                FootnoteDefinitionState::new(state)
+            ),
+        }
+    }
+}
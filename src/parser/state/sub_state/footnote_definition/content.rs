@@ -0,0 +1,80 @@
+use std::mem;
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::footnote_definition::label::LabelState;
+use crate::parser::state::sub_state::footnote_definition::newline::NewlineState;
+use crate::parser::state::sub_state::footnote_definition::FootnoteDefinitionSubState;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Footnote Definition: Content
+/// Line of the definition, either following the marker or an indented continuation line
+///
+/// Leading whitespace of the line is skipped, the rest is kept raw for the inline pass.
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding
+///
+/// ## Completion
+/// * DocumentEnding
+pub struct ContentState {
+    pub(crate) label: String,
+    pub(crate) lines: Vec<String>,
+    pub(crate) options: Rc<ParseOptions>,
+    line: String,
+}
+
+impl Transition for ContentState {
+    type Effect = NonDeterministicTransitionEffect<FootnoteDefinitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if !(self.line.is_empty() && character.is_blank()) {
+            self.line.push_str(&character.to_raw_string());
+        }
+
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        self.lines.push(mem::take(&mut self.line));
+        NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        self.lines.push(self.line);
+        FootnoteDefinitionSubState::complete(self.label, self.lines)
+            .end()
+    }
+}
+
+impl From<LabelState> for ContentState {
+    fn from(value: LabelState) -> Self {
+        Self {
+            label: value.label.unwrap_or_default(),
+            lines: vec![],
+            options: value.options,
+            line: String::new(),
+        }
+    }
+}
+
+impl From<NewlineState> for ContentState {
+    fn from(value: NewlineState) -> Self {
+        Self {
+            label: value.label,
+            lines: value.lines,
+            options: value.options,
+            line: String::new(),
+        }
+    }
+}
+
+impl From<ContentState> for FootnoteDefinitionSubState {
+    fn from(value: ContentState) -> Self {
+        FootnoteDefinitionSubState::Content(value)
+    }
+}
@@ -0,0 +1,105 @@
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::footnote_definition::content::ContentState;
+use crate::parser::state::sub_state::footnote_definition::FootnoteDefinitionSubState;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+const OPENING_BRACKET: char = '[';
+const CLOSING_BRACKET: char = ']';
+const CARET: char = '^';
+const COLON: char = ':';
+
+/// # Footnote Definition: Label
+/// `[^label]:` marker of a potential footnote definition
+///
+/// ## Transition
+/// * [ContentState] on `:` following the closing bracket
+///
+/// ## Dismissal
+/// * any character other than `^` after the opening bracket
+/// * whitespace or `[` inside the label, or a label exceeding the link label limit
+/// * empty label
+/// * any character other than `:` after the closing bracket
+/// * LineEnding
+/// * DocumentEnding
+pub struct LabelState {
+    pub(crate) label: Option<String>,
+    pub(crate) options: Rc<ParseOptions>,
+    length: usize,
+    closed: bool,
+}
+
+impl LabelState {
+    pub fn new(character: Character, options: Rc<ParseOptions>) -> Result<Self, Error> {
+        match character {
+            Character::Unescaped(OPENING_BRACKET) => Ok(Self {
+                label: None,
+                options,
+                length: 0,
+                closed: false,
+            }),
+            _ => Err(Error::Conversion),
+        }
+    }
+}
+
+impl Transition for LabelState {
+    type Effect = NonDeterministicTransitionEffect<FootnoteDefinitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        let Some(label) = self.label.as_mut() else {
+            return match character {
+                Character::Unescaped(CARET) => {
+                    self.label = Some(String::new());
+                    NonDeterministicTransitionEffect::pass(self)
+                }
+                _ => NonDeterministicTransitionEffect::dismiss(),
+            };
+        };
+
+        if self.closed {
+            return match character {
+                Character::Unescaped(COLON) => NonDeterministicTransitionEffect::transition_into::<ContentState>(self),
+                _ => NonDeterministicTransitionEffect::dismiss(),
+            };
+        }
+
+        match character {
+            Character::Unescaped(CLOSING_BRACKET) if !label.is_empty() => {
+                self.closed = true;
+                NonDeterministicTransitionEffect::pass(self)
+            }
+            Character::Unescaped(CLOSING_BRACKET | OPENING_BRACKET) => NonDeterministicTransitionEffect::dismiss(),
+            character if character.is_blank() => NonDeterministicTransitionEffect::dismiss(),
+            character => {
+                label.push_str(&character.to_raw_string());
+                self.length += 1;
+
+                match self.length > self.options.limits.max_link_label_length {
+                    true => NonDeterministicTransitionEffect::dismiss(),
+                    false => NonDeterministicTransitionEffect::pass(self),
+                }
+            }
+        }
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        NonDeterministicTransitionEffect::dismiss()
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        None
+    }
+}
+
+impl From<LabelState> for FootnoteDefinitionSubState {
+    fn from(value: LabelState) -> Self {
+        FootnoteDefinitionSubState::Label(value)
+    }
+}
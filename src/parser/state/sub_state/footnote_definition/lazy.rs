@@ -0,0 +1,98 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::footnote_definition::newline::NewlineState;
+use crate::parser::state::sub_state::footnote_definition::FootnoteDefinitionSubState;
+use crate::parser::state::sub_state::interruption;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+/// # Footnote Definition: Lazy continuation
+/// Unindented line directly following a line of the definition, continuing its paragraph unless
+/// it starts another block
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding of a line continuing the paragraph
+///
+/// ## Completion
+/// * on the blank following the opening sequence of an ATX heading, the line is handed back
+/// * LineEnding of a line starting another block, the line is handed back
+/// * DocumentEnding
+pub struct LazyState {
+    pub(crate) label: String,
+    pub(crate) lines: Vec<String>,
+    pub(crate) options: Rc<ParseOptions>,
+    line: Vec<Character>,
+}
+
+impl LazyState {
+    /// Completes the definition, handing the current line to `state`
+    fn hand_over(self, state: State) -> NonDeterministicTransitionEffect<FootnoteDefinitionSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::new(
+            FootnoteDefinitionSubState::Complete(state),
+            Some(FootnoteDefinitionSubState::definition(self.label, self.lines)),
+        )
+    }
+
+    fn push_line(&mut self) {
+        let line = self.line.iter()
+            .skip_while(|character| character.is_blank())
+            .map(Character::to_raw_string)
+            .collect();
+
+        self.lines.push(line);
+        self.line.clear();
+    }
+}
+
+impl Transition for LazyState {
+    type Effect = NonDeterministicTransitionEffect<FootnoteDefinitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push(character);
+
+        if let Some(state) = interruption::heading(&self.line, &self.options) {
+            return self.hand_over(state);
+        }
+
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, line_ending: LineEnding) -> Self::Effect {
+        match interruption::line(&self.line, line_ending, &self.options) {
+            Some(state) => self.hand_over(state),
+            None => {
+                self.push_line();
+                NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+            }
+        }
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        self.push_line();
+        FootnoteDefinitionSubState::complete(self.label, self.lines)
+            .end()
+    }
+}
+
+impl From<(NewlineState, usize)> for LazyState {
+    fn from((state, leading_spaces): (NewlineState, usize)) -> Self {
+        Self {
+            label: state.label,
+            lines: state.lines,
+            options: state.options,
+            line: vec![Character::Unescaped(unicode::SPACE); leading_spaces],
+        }
+    }
+}
+
+impl From<LazyState> for FootnoteDefinitionSubState {
+    fn from(value: LazyState) -> Self {
+        FootnoteDefinitionSubState::Lazy(value)
+    }
+}
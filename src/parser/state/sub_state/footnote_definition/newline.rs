@@ -0,0 +1,99 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::footnote_definition::content::ContentState;
+use crate::parser::state::sub_state::footnote_definition::lazy::LazyState;
+use crate::parser::state::sub_state::footnote_definition::FootnoteDefinitionSubState;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Footnote Definition: Newline
+/// Start of a line following the definition
+///
+/// Blank lines separate the paragraphs of the definition.
+///
+/// ## Transition
+/// * [ContentState] after an equivalent of 4 spaces
+/// * [LazyState] on non-whitespace character before an equivalent of 4 spaces, directly following
+///   a line of the definition
+///
+/// ## Completion
+/// * non-whitespace character before an equivalent of 4 spaces following a blank line
+/// * DocumentEnding
+pub struct NewlineState {
+    pub(crate) label: String,
+    pub(crate) lines: Vec<String>,
+    pub(crate) options: Rc<ParseOptions>,
+    leading_spaces: usize,
+}
+
+impl Transition for NewlineState {
+    type Effect = NonDeterministicTransitionEffect<FootnoteDefinitionSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.leading_spaces += character.space_count();
+
+        if self.leading_spaces >= 4 {
+            NonDeterministicTransitionEffect::transition_into::<ContentState>(self)
+        } else if character.is_blank() {
+            NonDeterministicTransitionEffect::pass(self)
+        } else if self.lines.last().is_some_and(|line| !line.is_empty()) {
+            let leading_spaces = self.leading_spaces;
+            LazyState::from((self, leading_spaces)).transition(character)
+        } else {
+            let (state, _) = State::from_leading_space_count(self.leading_spaces, self.options)
+                .transition(character)
+                .content();
+
+            NonDeterministicTransitionEffect::new(
+                FootnoteDefinitionSubState::Complete(state),
+                Some(FootnoteDefinitionSubState::definition(self.label, self.lines)),
+            )
+        }
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        if self.lines.last().is_some_and(|line| !line.is_empty()) {
+            self.lines.push(String::new());
+        }
+        self.leading_spaces = 0;
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        FootnoteDefinitionSubState::complete(self.label, self.lines)
+            .end()
+    }
+}
+
+impl From<ContentState> for NewlineState {
+    fn from(value: ContentState) -> Self {
+        Self {
+            label: value.label,
+            lines: value.lines,
+            options: value.options,
+            leading_spaces: 0,
+        }
+    }
+}
+
+impl From<LazyState> for NewlineState {
+    fn from(value: LazyState) -> Self {
+        Self {
+            label: value.label,
+            lines: value.lines,
+            options: value.options,
+            leading_spaces: 0,
+        }
+    }
+}
+
+impl From<NewlineState> for FootnoteDefinitionSubState {
+    fn from(value: NewlineState) -> Self {
+        FootnoteDefinitionSubState::NewLine(value)
+    }
+}
@@ -0,0 +1,174 @@
+mod start;
+mod content;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::raw_html::{self, BLOCK_TAGS, RAW_TEXT_TAGS};
+use crate::parser::state::handler::Handler;
+use crate::parser::state::sub_state::html_block::content::ContentState;
+use crate::parser::state::sub_state::html_block::start::StartState;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+const LESS_THAN: char = '<';
+
+pub type HtmlBlockState = Handler<SubState, HtmlBlockSubState>;
+
+impl TryFrom<(usize, Character)> for HtmlBlockState {
+    type Error = Error;
+
+    fn try_from((indentation, character): (usize, Character)) -> Result<Self, Self::Error> {
+        Ok(HtmlBlockState::new(StartState::new(indentation, character)?.into()))
+    }
+}
+
+impl From<HtmlBlockState> for SubState {
+    fn from(value: HtmlBlockState) -> Self {
+        SubState::HtmlBlock(value)
+    }
+}
+
+/// # HTML block kind
+/// Start condition of an HTML block, determining its end condition
+#[derive(Clone, Copy, PartialEq)]
+pub enum HtmlBlockKind {
+    /// `<pre`, `<script`, `<style` or `<textarea`, ends with the matching closing tag
+    RawText,
+    /// `<!--`, ends with `-->`
+    Comment,
+    /// `<?`, ends with `?>`
+    ProcessingInstruction,
+    /// `<!` and an ASCII letter, ends with `>`
+    Declaration,
+    /// `<![CDATA[`, ends with `]]>`
+    Cdata,
+    /// Opening or closing tag of a block level element, ends with a blank line
+    Block,
+    /// Any other complete tag alone on its line, ends with a blank line
+    Tag,
+}
+
+impl HtmlBlockKind {
+    /// Start condition met by a line without its indentation
+    pub fn detect(line: &[char]) -> Option<Self> {
+        if line.first() != Some(&LESS_THAN) {
+            return None;
+        }
+
+        let name_matches = |start: usize, names: &[&str]| {
+            let end = raw_html::tag_name(line, start)?;
+            let name = line[start..end].iter().collect::<String>().to_ascii_lowercase();
+            names.contains(&name.as_str()).then_some(end)
+        };
+
+        let raw_text = name_matches(1, &RAW_TEXT_TAGS)
+            .filter(|end| line.get(*end).map_or(true, |next| next.is_whitespace() || *next == '>'));
+
+        let closing = usize::from(line.get(1) == Some(&'/'));
+        let block = name_matches(1 + closing, &BLOCK_TAGS)
+            .filter(|end| match line.get(*end) {
+                None => true,
+                Some(next) if next.is_whitespace() || *next == '>' => true,
+                Some('/') => line.get(end + 1) == Some(&'>'),
+                _ => false,
+            });
+
+        if raw_text.is_some() {
+            Some(HtmlBlockKind::RawText)
+        } else if raw_html::starts_with(line, 0, "<!--") {
+            Some(HtmlBlockKind::Comment)
+        } else if raw_html::starts_with(line, 0, "<?") {
+            Some(HtmlBlockKind::ProcessingInstruction)
+        } else if raw_html::starts_with(line, 0, "<!") && line.get(2).is_some_and(char::is_ascii_alphabetic) {
+            Some(HtmlBlockKind::Declaration)
+        } else if raw_html::starts_with(line, 0, "<![CDATA[") {
+            Some(HtmlBlockKind::Cdata)
+        } else if block.is_some() {
+            Some(HtmlBlockKind::Block)
+        } else {
+            Self::detect_tag(line)
+        }
+    }
+
+    fn detect_tag(line: &[char]) -> Option<Self> {
+        let closing = usize::from(line.get(1) == Some(&'/'));
+        let name_end = raw_html::tag_name(line, 1 + closing)?;
+        let name = line[1 + closing..name_end].iter().collect::<String>().to_ascii_lowercase();
+
+        if RAW_TEXT_TAGS.contains(&name.as_str()) {
+            return None;
+        }
+
+        raw_html::open_tag(line, 0)
+            .or_else(|| raw_html::closing_tag(line, 0))
+            .filter(|end| line[*end..].iter().all(|character| character.is_whitespace()))
+            .map(|_| HtmlBlockKind::Tag)
+    }
+
+    /// Whether a line meets the end condition, blank lines are handled by the block itself
+    pub fn ends(&self, line: &[char]) -> bool {
+        match self {
+            HtmlBlockKind::RawText => RAW_TEXT_TAGS.iter()
+                .any(|name| raw_html::contains_ignore_case(line, &format!("</{name}>"))),
+            HtmlBlockKind::Comment => raw_html::contains_ignore_case(line, "-->"),
+            HtmlBlockKind::ProcessingInstruction => raw_html::contains_ignore_case(line, "?>"),
+            HtmlBlockKind::Declaration => line.contains(&'>'),
+            HtmlBlockKind::Cdata => raw_html::contains_ignore_case(line, "]]>"),
+            HtmlBlockKind::Block | HtmlBlockKind::Tag => false,
+        }
+    }
+}
+
+pub enum HtmlBlockSubState {
+    Start(StartState),
+    Content(ContentState),
+}
+
+impl HtmlBlockSubState {
+    /// An HTML block claims the block as soon as its first line meets a start condition
+    pub fn claims(&self) -> bool {
+        matches!(self, HtmlBlockSubState::Content(_))
+    }
+
+    pub fn complete(content: String) -> NonDeterministicTransitionEffect<HtmlBlockSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::complete::<Block>(Leaf::HtmlBlock {
+            text: content,
+        })
+    }
+}
+
+impl Transition for HtmlBlockSubState {
+    type Effect = NonDeterministicTransitionEffect<HtmlBlockSubState, Option<Block>>;
+
+    fn transition(self, character: Character) -> Self::Effect {
+        match self {
+            HtmlBlockSubState::Start(state) => state.transition(character),
+            HtmlBlockSubState::Content(state) => state.transition(character),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        match self {
+            HtmlBlockSubState::Start(state) => state.end_line(line_ending),
+            HtmlBlockSubState::Content(state) => state.end_line(line_ending),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self {
+            HtmlBlockSubState::Start(state) => state.end(),
+            HtmlBlockSubState::Content(state) => state.end(),
+        }
+    }
+}
+
+/// Raw source of a line
+pub fn raw_line(line: &[Character]) -> Vec<char> {
+    line.iter()
+        .flat_map(|character| character.to_raw_string().chars().collect::<Vec<_>>())
+        .collect()
+}
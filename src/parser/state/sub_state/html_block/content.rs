@@ -0,0 +1,84 @@
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::state::sub_state::html_block;
+use crate::parser::state::sub_state::html_block::start::StartState;
+use crate::parser::state::sub_state::html_block::{HtmlBlockKind, HtmlBlockSubState};
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+/// # HTML Block: Content
+/// Lines following the start line of an HTML block
+///
+/// ## Completion
+/// * on LineEnding when the line meets the end condition of the [HtmlBlockKind], including the line
+/// * on LineEnding after a blank line for [HtmlBlockKind::Block] and [HtmlBlockKind::Tag],
+///   excluding the line
+/// * DocumentEnding
+pub struct ContentState {
+    pub(crate) kind: HtmlBlockKind,
+    pub(crate) content: String,
+    pub(crate) line: Vec<Character>,
+}
+
+impl ContentState {
+    fn ends_with_blank_line(&self, line: &[char]) -> bool {
+        matches!(self.kind, HtmlBlockKind::Block | HtmlBlockKind::Tag)
+            && line.iter().all(|character| character.is_whitespace())
+    }
+}
+
+impl Transition for ContentState {
+    type Effect = NonDeterministicTransitionEffect<HtmlBlockSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push(character);
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        let line = html_block::raw_line(&self.line);
+
+        if self.ends_with_blank_line(&line) {
+            return HtmlBlockSubState::complete(self.content);
+        }
+
+        self.content.push(unicode::LINE_FEED);
+        self.content.extend(&line);
+
+        if self.kind.ends(&line) {
+            return HtmlBlockSubState::complete(self.content);
+        }
+
+        self.line.clear();
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        let line = html_block::raw_line(&self.line);
+
+        if !line.is_empty() && !self.ends_with_blank_line(&line) {
+            self.content.push(unicode::LINE_FEED);
+            self.content.extend(&line);
+        }
+
+        HtmlBlockSubState::complete(self.content).end()
+    }
+}
+
+impl From<(StartState, HtmlBlockKind)> for ContentState {
+    fn from((state, kind): (StartState, HtmlBlockKind)) -> Self {
+        Self {
+            kind,
+            content: state.content(),
+            line: vec![],
+        }
+    }
+}
+
+impl From<ContentState> for HtmlBlockSubState {
+    fn from(value: ContentState) -> Self {
+        HtmlBlockSubState::Content(value)
+    }
+}
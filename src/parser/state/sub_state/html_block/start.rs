@@ -0,0 +1,75 @@
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::state::sub_state::html_block;
+use crate::parser::state::sub_state::html_block::content::ContentState;
+use crate::parser::state::sub_state::html_block::{HtmlBlockKind, HtmlBlockSubState};
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+/// # HTML Block: Start
+/// First line of a potential HTML block, starting with `<`
+///
+/// ## Transition
+/// * [ContentState] on LineEnding when the line meets a start condition
+///
+/// ## Completion
+/// * on LineEnding when the line also meets the end condition of its [HtmlBlockKind]
+/// * DocumentEnding when the line meets a start condition
+///
+/// ## Dismissal
+/// * on LineEnding or DocumentEnding when the line meets no start condition
+pub struct StartState {
+    pub(crate) indentation: usize,
+    pub(crate) line: Vec<Character>,
+}
+
+impl StartState {
+    pub fn new(indentation: usize, character: Character) -> Result<Self, Error> {
+        match character {
+            Character::Unescaped(html_block::LESS_THAN) => Ok(Self {
+                indentation,
+                line: vec![character],
+            }),
+            _ => Err(Error::Conversion),
+        }
+    }
+
+    pub(crate) fn content(&self) -> String {
+        let mut content = unicode::SPACE.to_string().repeat(self.indentation);
+        content.extend(html_block::raw_line(&self.line));
+        content
+    }
+}
+
+impl Transition for StartState {
+    type Effect = NonDeterministicTransitionEffect<HtmlBlockSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push(character);
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        let line = html_block::raw_line(&self.line);
+
+        match HtmlBlockKind::detect(&line) {
+            Some(kind) if kind.ends(&line) => HtmlBlockSubState::complete(self.content()),
+            Some(kind) => NonDeterministicTransitionEffect::transition_into::<ContentState>((self, kind)),
+            None => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        HtmlBlockKind::detect(&html_block::raw_line(&self.line))
+            .and_then(|_| HtmlBlockSubState::complete(self.content()).end())
+    }
+}
+
+impl From<StartState> for HtmlBlockSubState {
+    fn from(value: StartState) -> Self {
+        HtmlBlockSubState::Start(value)
+    }
+}
@@ -2,10 +2,13 @@ mod content;
 mod newline;
 mod blank;
 
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::{LineEnding, State};
 use crate::parser::state::handler::Handler;
 use crate::parser::state::sub_state::indented_code_block::blank::BlankLineState;
@@ -17,10 +20,10 @@ use crate::unicode;
 
 pub type IndentedCodeBlockState = Handler<SubState, IndentedCodeBlockSubState>;
 
-impl Default for IndentedCodeBlockState {
-    fn default() -> Self {
+impl From<Rc<ParseOptions>> for IndentedCodeBlockState {
+    fn from(value: Rc<ParseOptions>) -> Self {
         IndentedCodeBlockState::new(
-            IndentedCodeBlockSubState::default()
+            IndentedCodeBlockSubState::from(value)
         )
     }
 }
@@ -90,9 +93,9 @@ impl Transition for IndentedCodeBlockSubState {
 }
 
 
-impl Default for IndentedCodeBlockSubState {
-    fn default() -> Self {
-        IndentedCodeBlockSubState::Blank(BlankLineState::default())
+impl From<Rc<ParseOptions>> for IndentedCodeBlockSubState {
+    fn from(value: Rc<ParseOptions>) -> Self {
+        IndentedCodeBlockSubState::Blank(BlankLineState::from(value))
     }
 }
 
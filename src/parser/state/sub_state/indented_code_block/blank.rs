@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::indented_code_block::content::ContentState;
 use crate::parser::state::sub_state::indented_code_block::IndentedCodeBlockSubState;
 use crate::parser::state::sub_state::indented_code_block::newline::NewlineState;
@@ -23,6 +26,7 @@ use crate::unicode;
 /// * LineEnding when content is empty
 pub struct BlankLineState {
     pub(crate) content: String,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for BlankLineState {
@@ -56,10 +60,11 @@ impl Transition for BlankLineState {
     }
 }
 
-impl Default for BlankLineState {
-    fn default() -> Self {
+impl From<Rc<ParseOptions>> for BlankLineState {
+    fn from(value: Rc<ParseOptions>) -> Self {
         Self {
             content: "".to_string(),
+            options: value,
         }
     }
 }
@@ -68,6 +73,7 @@ impl From<NewlineState> for BlankLineState {
     fn from(value: NewlineState) -> Self {
         Self {
             content: value.content,
+            options: value.options,
         }
     }
 }
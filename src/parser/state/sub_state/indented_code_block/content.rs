@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::indented_code_block::blank::BlankLineState;
 use crate::parser::state::sub_state::indented_code_block::IndentedCodeBlockSubState;
 use crate::parser::state::sub_state::indented_code_block::newline::NewlineState;
@@ -17,6 +20,7 @@ use crate::parser::transition::{Transition, TransitionEffect};
 /// * DocumentEnding
 pub struct ContentState {
     pub(crate) content: String,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for ContentState {
@@ -42,6 +46,7 @@ impl From<BlankLineState> for ContentState {
     fn from(value: BlankLineState) -> Self {
         Self {
             content: value.content,
+            options: value.options,
         }
     }
 }
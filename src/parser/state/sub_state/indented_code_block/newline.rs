@@ -1,8 +1,11 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::indented_code_block::blank::BlankLineState;
 use crate::parser::state::sub_state::indented_code_block::content::ContentState;
 use crate::parser::state::sub_state::indented_code_block::IndentedCodeBlockSubState;
@@ -21,6 +24,7 @@ use crate::unicode;
 /// * DocumentEnding
 pub struct NewlineState {
     pub(crate) content: String,
+    pub(crate) options: Rc<ParseOptions>,
     leading_spaces: usize,
 }
 
@@ -35,7 +39,7 @@ impl Transition for NewlineState {
         } else if character.is_blank() {
             NonDeterministicTransitionEffect::pass(self)
         } else {
-            let (state, _) = State::from_leading_space_count(self.leading_spaces)
+            let (state, _) = State::from_leading_space_count(self.leading_spaces, self.options)
                 .transition(character)
                 .content();
 
@@ -67,6 +71,7 @@ impl From<ContentState> for NewlineState {
     fn from(state: ContentState) -> Self {
         Self {
             content: state.content,
+            options: state.options,
             leading_spaces: 0,
         }
     }
@@ -76,6 +81,7 @@ impl From<BlankLineState> for NewlineState {
     fn from(value: BlankLineState) -> Self {
         Self {
             content: value.content,
+            options: value.options,
             leading_spaces: 0,
         }
     }
@@ -0,0 +1,75 @@
+//! Lines ending a block whose lines continue as text, such as a table body or a paragraph, by
+//! starting another block
+
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::default::DefaultState;
+use crate::parser::state::sub_state::atx_heading::HASHTAG;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+const GREATER_THAN: char = '>';
+
+/// State of the ATX heading opened by the line so far, as soon as its opening sequence is followed
+/// by a blank
+///
+/// The heading is handed over before the end of its line, so that it is located on its own line.
+pub fn heading(line: &[Character], options: &Rc<ParseOptions>) -> Option<State> {
+    (options.constructs.atx_heading && opens_atx_heading(line))
+        .then(|| replay(line, options).0)
+}
+
+/// State taking over a complete line starting another block, [None] for a line continuing the
+/// text of the block
+///
+/// A block completed on the line itself, like a thematic break, is emitted by the next transition
+/// of the returned state. Block quotes are not parsed, their lines still start another block.
+pub fn line(line: &[Character], line_ending: LineEnding, options: &Rc<ParseOptions>) -> Option<State> {
+    let (state, block) = replay(line, options);
+    let (state, completed) = state.end_line(line_ending).content();
+
+    match block.or(completed) {
+        Some(block) => Some(DefaultState::from((block, options.clone())).into()),
+        None if state.interrupts() || opens_block_quote(line) => Some(state),
+        None => None,
+    }
+}
+
+/// Runs a line as the first line of a new block
+fn replay(line: &[Character], options: &Rc<ParseOptions>) -> (State, Option<Block>) {
+    line.iter()
+        .fold((State::from(options.clone()), None), |(state, block), character| {
+            let (state, completed) = state.transition(character.clone()).content();
+            (state, block.or(completed))
+        })
+}
+
+/// Characters of the line following up to 3 spaces of indentation
+fn unindented(line: &[Character]) -> Option<&[Character]> {
+    let indentation = line.iter()
+        .take_while(|character| matches!(character, Character::Unescaped(unicode::SPACE)))
+        .count();
+
+    (indentation < 4).then(|| &line[indentation..])
+}
+
+/// Whether the line so far is the opening sequence of an ATX heading followed by a blank
+fn opens_atx_heading(line: &[Character]) -> bool {
+    let Some((last, opening)) = unindented(line).and_then(<[Character]>::split_last) else {
+        return false;
+    };
+
+    last.is_blank()
+        && (1..=6).contains(&opening.len())
+        && opening.iter().all(|character| matches!(character, Character::Unescaped(HASHTAG)))
+}
+
+/// Whether the line starts a block quote
+fn opens_block_quote(line: &[Character]) -> bool {
+    matches!(unindented(line).and_then(<[Character]>::first), Some(Character::Unescaped(GREATER_THAN)))
+}
@@ -0,0 +1,211 @@
+mod marker;
+mod content;
+mod newline;
+mod lazy;
+
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::container::{Container, ContainerKind, ListKind};
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::handler::Handler;
+use crate::parser::state::sub_state::list::content::ContentState;
+use crate::parser::state::sub_state::list::lazy::LazyState;
+use crate::parser::state::sub_state::list::marker::MarkerState;
+use crate::parser::state::sub_state::list::newline::NewlineState;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+pub type ListState = Handler<SubState, ListSubState>;
+
+impl TryFrom<(usize, Character, Rc<ParseOptions>)> for ListState {
+    type Error = Error;
+
+    fn try_from((leading_spaces, character, options): (usize, Character, Rc<ParseOptions>)) -> Result<Self, Self::Error> {
+        Ok(ListState::new(MarkerState::new(leading_spaces, character, options)?.into()))
+    }
+}
+
+impl From<ListState> for SubState {
+    fn from(value: ListState) -> Self {
+        value.state().into()
+    }
+}
+
+/// # Items
+/// Items of a list sharing the same kind of marker
+///
+/// Every item holds its lines, an empty line separates its paragraphs. Nested blocks are not
+/// parsed, the lines of an item are kept as paragraphs.
+pub struct Items {
+    pub(crate) kind: ListKind,
+    /// Bullet character, or delimiter following the number of an ordered list item
+    pub(crate) delimiter: char,
+    pub(crate) items: Vec<Vec<String>>,
+    /// Indentation of the content of the last item, lines indented as much continue it
+    pub(crate) indentation: usize,
+    /// Whether a blank line separates items or paragraphs of an item
+    pub(crate) loose: bool,
+}
+
+impl Items {
+    /// Whether the list may interrupt a paragraph, only lists starting with a non-empty item
+    /// numbered 1 if ordered do
+    fn interrupts(&self) -> bool {
+        !matches!(self.kind, ListKind::Ordered { start } if start != 1)
+            && self.items.first().is_some_and(|item| !item.is_empty())
+    }
+
+    fn block(self) -> Block {
+        let items = self.items.into_iter()
+            .map(|lines| {
+                let paragraphs = lines.split(String::is_empty)
+                    .filter(|paragraph| !paragraph.is_empty())
+                    .map(|paragraph| Leaf::Paragraph {
+                        content: vec![Inline::Raw(paragraph.join("\n"))],
+                    }.into())
+                    .collect();
+
+                Container::new(ContainerKind::ListItem, paragraphs).into()
+            })
+            .collect();
+
+        Container::new(
+            ContainerKind::List {
+                kind: self.kind,
+                tight: !self.loose,
+            },
+            items,
+        ).into()
+    }
+}
+
+pub enum ListSubState {
+    Marker(MarkerState),
+    Content(ContentState),
+    NewLine(NewlineState),
+    Lazy(LazyState),
+    Complete(State),
+}
+
+impl ListSubState {
+    /// A list claims the block once its first line ended, a line like `- - -` is a thematic break
+    pub fn claims(&self) -> bool {
+        match self {
+            ListSubState::Marker(state) => state.items.is_some(),
+            ListSubState::Content(state) => state.items.items.len() > 1 || !state.follows_marker(),
+            ListSubState::NewLine(_) | ListSubState::Lazy(_) => true,
+            ListSubState::Complete(_) => false,
+        }
+    }
+
+    pub fn interrupts(&self) -> bool {
+        match self {
+            ListSubState::Marker(state) => state.items.as_ref().is_some_and(Items::interrupts),
+            ListSubState::Content(state) => state.items.interrupts(),
+            ListSubState::NewLine(state) => state.items.interrupts(),
+            ListSubState::Lazy(state) => state.items.interrupts(),
+            ListSubState::Complete(_) => false,
+        }
+    }
+
+    pub fn complete(items: Items) -> NonDeterministicTransitionEffect<ListSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::complete::<Block>(items.block())
+    }
+
+    /// Completes the list, handing the current line to `state`
+    fn hand_over(items: Items, state: State) -> NonDeterministicTransitionEffect<ListSubState, Option<Block>> {
+        NonDeterministicTransitionEffect::new(
+            ListSubState::Complete(state),
+            Some(items.block()),
+        )
+    }
+}
+
+impl Transition for ListSubState {
+    type Effect = NonDeterministicTransitionEffect<ListSubState, Option<Block>>;
+
+    fn transition(self, character: Character) -> Self::Effect {
+        match self {
+            ListSubState::Marker(state) => state.transition(character),
+            ListSubState::Content(state) => state.transition(character),
+            ListSubState::NewLine(state) => state.transition(character),
+            ListSubState::Lazy(state) => state.transition(character),
+            ListSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        match self {
+            ListSubState::Marker(state) => state.end_line(line_ending),
+            ListSubState::Content(state) => state.end_line(line_ending),
+            ListSubState::NewLine(state) => state.end_line(line_ending),
+            ListSubState::Lazy(state) => state.end_line(line_ending),
+            ListSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self {
+            ListSubState::Marker(state) => state.end(),
+            ListSubState::Content(state) => state.end(),
+            ListSubState::NewLine(state) => state.end(),
+            ListSubState::Lazy(state) => state.end(),
+            ListSubState::Complete(_) => None,
+        }
+    }
+}
+
+impl From<ListSubState> for SubState {
+    fn from(value: ListSubState) -> Self {
+        match value {
+            ListSubState::Complete(state) => SubState::IntoSuper(state),
+            state => SubState::List(
+                ListState::new(state)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::Parser;
+
+    #[test]
+    /// Lists: tight and loose bullet lists
+    fn test_list_bullet() {
+        let parser = Parser::from_reader("- foo\n- bar\nbaz\n\n* loose\n\n* items\n\n  second paragraph\n".as_bytes());
+        assert_eq!(
+            "<ul>\n<li>foo</li>\n<li>bar\nbaz</li>\n</ul>\n\
+            <ul>\n<li>\n<p>loose</p>\n</li>\n<li>\n<p>items</p>\n<p>second paragraph</p>\n</li>\n</ul>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Lists: ordered lists keep their start number, another delimiter starts another list
+    fn test_list_ordered() {
+        let parser = Parser::from_reader("3) foo\n4) bar\n1. baz\n".as_bytes());
+        assert_eq!(
+            "<ol start=\"3\">\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol>\n<li>baz</li>\n</ol>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+
+    #[test]
+    /// Lists: thematic breaks and headings
+    fn test_list_thematic_break() {
+        let parser = Parser::from_reader("- - -\n- foo\n---\n- bar\n# Heading\n".as_bytes());
+        assert_eq!(
+            "<hr />\n<ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>\n<h1>Heading</h1>\n",
+            parser.parse_to_string().unwrap_or("".into())
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::mem;
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::list::newline::NewlineState;
+use crate::parser::state::sub_state::list::{Items, ListSubState};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # List: Content
+/// Line of an item, either following its marker or an indented continuation line
+///
+/// Leading whitespace of the line is skipped, the rest is kept raw for the inline pass. The
+/// whitespace following the marker sets the indentation of the continuation lines, unless the
+/// content is indented by more than 4 spaces.
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding
+///
+/// ## Completion
+/// * DocumentEnding
+pub struct ContentState {
+    pub(crate) items: Items,
+    pub(crate) options: Rc<ParseOptions>,
+    line: String,
+    /// Width of the marker and its indentation, [None] on a continuation line
+    marker_width: Option<usize>,
+    spaces: usize,
+}
+
+impl ContentState {
+    /// Whether the line follows the marker of its item
+    pub fn follows_marker(&self) -> bool {
+        self.marker_width.is_some()
+    }
+
+    fn push_line(&mut self) {
+        if let Some(item) = self.items.items.last_mut() {
+            item.push(mem::take(&mut self.line));
+        }
+    }
+}
+
+impl Transition for ContentState {
+    type Effect = NonDeterministicTransitionEffect<ListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if self.line.is_empty() && character.is_blank() {
+            self.spaces += character.space_count();
+            return NonDeterministicTransitionEffect::pass(self);
+        }
+
+        if let (true, Some(width)) = (self.line.is_empty(), self.marker_width) {
+            self.items.indentation = match self.spaces {
+                spaces @ 1..=4 => width + spaces,
+                _ => width + 1,
+            };
+        }
+
+        self.line.push_str(&character.to_raw_string());
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        if !self.line.is_empty() {
+            self.push_line();
+        }
+
+        NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        if !self.line.is_empty() {
+            self.push_line();
+        }
+
+        ListSubState::complete(self.items)
+            .end()
+    }
+}
+
+impl From<(Items, Rc<ParseOptions>, usize, usize)> for ContentState {
+    fn from((items, options, marker_width, spaces): (Items, Rc<ParseOptions>, usize, usize)) -> Self {
+        Self {
+            items,
+            options,
+            line: String::new(),
+            marker_width: Some(marker_width),
+            spaces,
+        }
+    }
+}
+
+impl From<NewlineState> for ContentState {
+    fn from(value: NewlineState) -> Self {
+        Self {
+            items: value.items,
+            options: value.options,
+            line: String::new(),
+            marker_width: None,
+            spaces: 0,
+        }
+    }
+}
+
+impl From<ContentState> for ListSubState {
+    fn from(value: ContentState) -> Self {
+        ListSubState::Content(value)
+    }
+}
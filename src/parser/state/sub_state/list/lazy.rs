@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::interruption;
+use crate::parser::state::sub_state::list::newline::NewlineState;
+use crate::parser::state::sub_state::list::{Items, ListSubState};
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # List: Lazy continuation
+/// Line indented less than the content of the item directly following a line of the item,
+/// continuing its paragraph unless it starts another block
+///
+/// ## Transition
+/// * [NewlineState] on LineEnding of a line continuing the paragraph
+///
+/// ## Completion
+/// * on the blank following the opening sequence of an ATX heading, the line is handed back
+/// * LineEnding of a line starting another block, the line is handed back
+/// * DocumentEnding
+pub struct LazyState {
+    pub(crate) items: Items,
+    pub(crate) options: Rc<ParseOptions>,
+    line: Vec<Character>,
+}
+
+impl LazyState {
+    fn push_line(&mut self) {
+        let line = self.line.iter()
+            .skip_while(|character| character.is_blank())
+            .map(Character::to_raw_string)
+            .collect();
+
+        if let Some(item) = self.items.items.last_mut() {
+            item.push(line);
+        }
+        self.line.clear();
+    }
+}
+
+impl Transition for LazyState {
+    type Effect = NonDeterministicTransitionEffect<ListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push(character);
+
+        if let Some(state) = interruption::heading(&self.line, &self.options) {
+            return ListSubState::hand_over(self.items, state);
+        }
+
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, line_ending: LineEnding) -> Self::Effect {
+        match interruption::line(&self.line, line_ending, &self.options) {
+            Some(state) => ListSubState::hand_over(self.items, state),
+            None => {
+                self.push_line();
+                NonDeterministicTransitionEffect::transition_into::<NewlineState>(self)
+            }
+        }
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        self.push_line();
+        ListSubState::complete(self.items)
+            .end()
+    }
+}
+
+impl From<(Items, Rc<ParseOptions>, Vec<Character>)> for LazyState {
+    fn from((items, options, line): (Items, Rc<ParseOptions>, Vec<Character>)) -> Self {
+        Self {
+            items,
+            options,
+            line,
+        }
+    }
+}
+
+impl From<LazyState> for ListSubState {
+    fn from(value: LazyState) -> Self {
+        ListSubState::Lazy(value)
+    }
+}
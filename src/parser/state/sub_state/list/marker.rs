@@ -0,0 +1,213 @@
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::container::ListKind;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::default::DefaultState;
+use crate::parser::state::sub_state::list::content::ContentState;
+use crate::parser::state::sub_state::list::lazy::LazyState;
+use crate::parser::state::sub_state::list::newline::NewlineState;
+use crate::parser::state::sub_state::list::{Items, ListSubState};
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+const BULLETS: [char; 3] = ['-', '+', '*'];
+const DELIMITERS: [char; 2] = ['.', ')'];
+const MAX_NUMBER_LENGTH: usize = 9;
+
+/// # List: Marker
+/// Bullet, or number of up to 9 digits followed by `.` or `)`, opening a list item
+///
+/// Further items share the bullet, or the delimiter of the number, of the first item.
+///
+/// ## Transition
+/// * [ContentState] on whitespace character following the marker
+/// * [NewlineState] on LineEnding following the marker, with an empty item
+/// * [LazyState] on any other character of a line directly following a line of an item
+///
+/// ## Completion
+/// * any other character of a line following a blank line, the line is handed back
+/// * DocumentEnding
+///
+/// ## Dismissal
+/// * any other character of the first line
+pub struct MarkerState {
+    /// Items before this one, [None] for the first item
+    pub(crate) items: Option<Items>,
+    pub(crate) options: Rc<ParseOptions>,
+    leading_spaces: usize,
+    /// Whether a blank line precedes the marker
+    blank: bool,
+    marker: String,
+}
+
+impl MarkerState {
+    pub fn new(leading_spaces: usize, character: Character, options: Rc<ParseOptions>) -> Result<Self, Error> {
+        match MarkerState::opens(&character) {
+            true => Ok(Self {
+                items: None,
+                options,
+                leading_spaces,
+                blank: false,
+                marker: character.to_raw_string(),
+            }),
+            false => Err(Error::StartState),
+        }
+    }
+
+    /// Whether the character may start the marker of a list item
+    pub fn opens(character: &Character) -> bool {
+        matches!(character, Character::Unescaped(character) if BULLETS.contains(character) || character.is_ascii_digit())
+    }
+
+    /// Kind and delimiter of the item, [None] while the marker is incomplete or when it does not
+    /// continue the list
+    fn item(&self) -> Option<(ListKind, char)> {
+        let delimiter = self.marker.chars().last()?;
+
+        let kind = match BULLETS.contains(&delimiter) {
+            true => ListKind::Bullet,
+            false => ListKind::Ordered {
+                start: self.marker.strip_suffix(DELIMITERS)?.parse().ok()?,
+            },
+        };
+
+        match &self.items {
+            Some(items) if items.delimiter != delimiter => None,
+            _ => Some((kind, delimiter)),
+        }
+    }
+
+    /// Whether the character extends the number of an ordered list item
+    fn extends(&self, character: &Character) -> bool {
+        let Character::Unescaped(character) = character else {
+            return false;
+        };
+
+        self.marker.chars().all(|character| character.is_ascii_digit())
+            && (character.is_ascii_digit() && self.marker.len() < MAX_NUMBER_LENGTH || DELIMITERS.contains(character))
+    }
+
+    /// Items with the item opened by the marker appended, and the width of the marker
+    fn open(self, kind: ListKind, delimiter: char) -> (Items, usize) {
+        let width = self.leading_spaces + self.marker.len();
+
+        let items = match self.items {
+            Some(mut items) => {
+                items.items.push(vec![]);
+                items.indentation = width + 1;
+                items.loose |= self.blank;
+                items
+            }
+            None => Items {
+                kind,
+                delimiter,
+                items: vec![vec![]],
+                indentation: width + 1,
+                loose: false,
+            },
+        };
+
+        (items, width)
+    }
+
+    /// Characters of the line so far
+    fn line(&self) -> Vec<Character> {
+        let mut line = vec![Character::Unescaped(unicode::SPACE); self.leading_spaces];
+        line.extend(self.marker.chars().map(Character::Unescaped));
+        line
+    }
+
+    /// State running the line so far as the first line of a new block
+    fn replay(&self, character: Option<Character>) -> State {
+        self.line().into_iter()
+            .chain(character)
+            .fold(State::from(self.options.clone()), |state, character| state.transition(character).content().0)
+    }
+}
+
+impl Transition for MarkerState {
+    type Effect = NonDeterministicTransitionEffect<ListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if self.extends(&character) {
+            self.marker.push_str(&character.to_raw_string());
+            return NonDeterministicTransitionEffect::pass(self);
+        }
+
+        if let (Some((kind, delimiter)), true) = (self.item(), character.is_blank()) {
+            let spaces = character.space_count();
+            let options = self.options.clone();
+            let (items, width) = self.open(kind, delimiter);
+
+            return NonDeterministicTransitionEffect::transition_into::<ContentState>((items, options, width, spaces));
+        }
+
+        let replayed = self.blank.then(|| self.replay(Some(character.clone())));
+        let line = self.line();
+        let Some(items) = self.items else {
+            return NonDeterministicTransitionEffect::dismiss();
+        };
+
+        match replayed {
+            Some(state) => ListSubState::hand_over(items, state),
+            None => LazyState::from((items, self.options, line)).transition(character),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        if let Some((kind, delimiter)) = self.item() {
+            let options = self.options.clone();
+            let (items, _) = self.open(kind, delimiter);
+
+            return NonDeterministicTransitionEffect::transition_into::<NewlineState>((items, options));
+        }
+
+        let replayed = self.blank.then(|| self.replay(None).end_line(line_ending).content());
+        let line = self.line();
+        let Some(items) = self.items else {
+            return NonDeterministicTransitionEffect::dismiss();
+        };
+
+        match replayed {
+            Some((_, Some(block))) => ListSubState::hand_over(items, DefaultState::from((block, self.options)).into()),
+            Some((state, None)) => ListSubState::hand_over(items, state),
+            None => LazyState::from((items, self.options, line)).end_line(line_ending),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        if let Some((kind, delimiter)) = self.item() {
+            return Some(self.open(kind, delimiter).0.block());
+        }
+
+        let line = self.line();
+        match self.blank {
+            true => self.items.map(Items::block),
+            false => LazyState::from((self.items?, self.options, line)).end(),
+        }
+    }
+}
+
+impl From<(NewlineState, Character)> for MarkerState {
+    fn from((state, character): (NewlineState, Character)) -> Self {
+        Self {
+            items: Some(state.items),
+            options: state.options,
+            leading_spaces: state.leading_spaces,
+            blank: state.blank,
+            marker: character.to_raw_string(),
+        }
+    }
+}
+
+impl From<MarkerState> for ListSubState {
+    fn from(value: MarkerState) -> Self {
+        ListSubState::Marker(value)
+    }
+}
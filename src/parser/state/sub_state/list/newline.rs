@@ -0,0 +1,119 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::list::content::ContentState;
+use crate::parser::state::sub_state::list::lazy::LazyState;
+use crate::parser::state::sub_state::list::marker::MarkerState;
+use crate::parser::state::sub_state::list::{Items, ListSubState};
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+/// # List: Newline
+/// Start of a line following an item
+///
+/// Blank lines separate the paragraphs of an item, a blank line followed by another paragraph or
+/// item makes the list loose.
+///
+/// ## Transition
+/// * [ContentState] on non-whitespace character indented as much as the content of the item
+/// * [MarkerState] on a bullet or digit before an equivalent of 4 spaces
+/// * [LazyState] on any other non-whitespace character, directly following a line of an item
+///
+/// ## Completion
+/// * any other non-whitespace character following a blank line or an empty item, the line is
+///   handed back
+/// * DocumentEnding
+pub struct NewlineState {
+    pub(crate) items: Items,
+    pub(crate) options: Rc<ParseOptions>,
+    pub(crate) leading_spaces: usize,
+    /// Whether a blank line precedes the current one
+    pub(crate) blank: bool,
+}
+
+impl Transition for NewlineState {
+    type Effect = NonDeterministicTransitionEffect<ListSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        if character.is_blank() {
+            self.leading_spaces += character.space_count();
+            return NonDeterministicTransitionEffect::pass(self);
+        }
+
+        if self.leading_spaces >= self.items.indentation {
+            if let (true, Some(item)) = (self.blank, self.items.items.last_mut()) {
+                item.push(String::new());
+                self.items.loose = true;
+            }
+
+            return ContentState::from(self).transition(character);
+        }
+
+        if self.leading_spaces < 4 && MarkerState::opens(&character) {
+            return NonDeterministicTransitionEffect::pass(MarkerState::from((self, character)));
+        }
+
+        let lazy = !self.blank && self.items.items.last()
+            .and_then(|item| item.last())
+            .is_some_and(|line| !line.is_empty());
+
+        match lazy {
+            true => {
+                let line = vec![Character::Unescaped(unicode::SPACE); self.leading_spaces];
+                LazyState::from((self.items, self.options, line)).transition(character)
+            }
+            false => {
+                let (state, _) = State::from_leading_space_count(self.leading_spaces, self.options)
+                    .transition(character)
+                    .content();
+
+                ListSubState::hand_over(self.items, state)
+            }
+        }
+    }
+
+    fn end_line(mut self, _: LineEnding) -> Self::Effect {
+        self.blank = true;
+        self.leading_spaces = 0;
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        ListSubState::complete(self.items)
+            .end()
+    }
+}
+
+impl From<(Items, Rc<ParseOptions>)> for NewlineState {
+    fn from((items, options): (Items, Rc<ParseOptions>)) -> Self {
+        Self {
+            items,
+            options,
+            leading_spaces: 0,
+            blank: false,
+        }
+    }
+}
+
+impl From<ContentState> for NewlineState {
+    fn from(value: ContentState) -> Self {
+        (value.items, value.options).into()
+    }
+}
+
+impl From<LazyState> for NewlineState {
+    fn from(value: LazyState) -> Self {
+        (value.items, value.options).into()
+    }
+}
+
+impl From<NewlineState> for ListSubState {
+    fn from(value: NewlineState) -> Self {
+        ListSubState::NewLine(value)
+    }
+}
@@ -3,12 +3,18 @@ mod newline;
 mod underline;
 mod trailing;
 
+use std::rc::Rc;
+
 use crate::error::Error;
 use crate::parser::character::Character;
+use crate::parser::document::attributes::Attributes;
 use crate::parser::document::block::Block;
+use crate::parser::document::heading::HeadingMeta;
+use crate::parser::document::inline::Inline;
 use crate::parser::document::leaf::Leaf;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::handler::Handler;
 use crate::parser::state::sub_state::stx_heading::content::ContentState;
 use crate::parser::state::sub_state::stx_heading::newline::NewlineState;
@@ -56,8 +62,8 @@ impl TryFrom<&Character> for UnderlineCharacter {
 
 pub type STXHeadingState = Handler<SubState, STXHeadingSubState>;
 
-impl From<Character> for STXHeadingState {
-    fn from(value: Character) -> Self {
+impl From<(Character, Rc<ParseOptions>)> for STXHeadingState {
+    fn from(value: (Character, Rc<ParseOptions>)) -> Self {
         Handler::new(STXHeadingSubState::from(value))
     }
 }
@@ -78,15 +84,22 @@ pub enum STXHeadingSubState {
 impl STXHeadingSubState {
     pub fn complete(state: impl Into<STXHeadingSubState>) -> NonDeterministicTransitionEffect<STXHeadingSubState, Option<Block>> {
         let state = state.into();
-        let (content, underline) = match state {
-            STXHeadingSubState::Underline(state) => (state.content, state.underline_character),
-            STXHeadingSubState::Trailing(state) => (state.content, state.underline_character),
+        let (content, underline, options) = match state {
+            STXHeadingSubState::Underline(state) => (state.content, state.underline_character, state.options),
+            STXHeadingSubState::Trailing(state) => (state.content, state.underline_character, state.options),
             _ => unreachable!()
         };
 
+        let (content, attributes) = match options.constructs.attributes {
+            true => Attributes::split_trailing(&content)
+                .unwrap_or((content.trim_end(), Attributes::default())),
+            false => (content.trim_end(), Attributes::default()),
+        };
+
         NonDeterministicTransitionEffect::complete::<Block>(Leaf::SetextHeading {
             level: underline.level(),
-            text: content.trim_end().to_string(),
+            content: vec![Inline::Raw(content.to_string())],
+            meta: HeadingMeta::from_attributes(attributes),
         })
     }
 }
@@ -122,8 +135,8 @@ impl Transition for STXHeadingSubState {
     }
 }
 
-impl From<Character> for STXHeadingSubState {
-    fn from(value: Character) -> Self {
-        ContentState::new(value).into()
+impl From<(Character, Rc<ParseOptions>)> for STXHeadingSubState {
+    fn from((character, options): (Character, Rc<ParseOptions>)) -> Self {
+        ContentState::new(character, options).into()
     }
 }
\ No newline at end of file
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::stx_heading::newline::NewlineState;
 use crate::parser::state::sub_state::stx_heading::STXHeadingSubState;
 use crate::parser::state::sub_state::stx_heading::trailing::TrailingState;
@@ -18,12 +21,14 @@ use crate::parser::transition::{Transition, TransitionEffect};
 /// * DocumentEnding
 pub struct ContentState {
     pub(crate) content: String,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl ContentState {
-    pub fn new(character: Character) -> Self {
+    pub fn new(character: Character, options: Rc<ParseOptions>) -> Self {
         Self {
-            content: character.to_string(),
+            content: character.to_raw_string(),
+            options,
         }
     }
 }
@@ -32,7 +37,7 @@ impl Transition for ContentState {
     type Effect = NonDeterministicTransitionEffect<STXHeadingSubState, Option<Block>>;
 
     fn transition(mut self, character: Character) -> Self::Effect {
-        self.content.push_str(&*character.to_string());
+        self.content.push_str(&*character.to_raw_string());
         NonDeterministicTransitionEffect::pass(self)
     }
 
@@ -49,6 +54,7 @@ impl From<NewlineState> for ContentState {
     fn from(value: NewlineState) -> Self {
         Self {
             content: value.content,
+            options: value.options,
         }
     }
 }
@@ -57,6 +63,7 @@ impl From<UnderlineState> for ContentState {
     fn from(value: UnderlineState) -> Self {
         Self {
             content: value.content,
+            options: value.options,
         }
     }
 }
@@ -65,6 +72,7 @@ impl From<TrailingState> for ContentState {
     fn from(value: TrailingState) -> Self {
         Self {
             content: value.content,
+            options: value.options,
         }
     }
 }
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::stx_heading::{STXHeadingSubState, UnderlineCharacter};
 use crate::parser::state::sub_state::stx_heading::content::ContentState;
 use crate::parser::state::sub_state::stx_heading::underline::UnderlineState;
@@ -22,6 +25,7 @@ pub struct NewlineState {
     pub(crate) content: String,
     pub(crate) temp: String,
     pub(crate) leading_spaces: usize,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for NewlineState {
@@ -42,7 +46,7 @@ impl Transition for NewlineState {
             }
         } else {
             self.content.push_str(&*self.temp);
-            self.content.push_str(&*character.to_string());
+            self.content.push_str(&*character.to_raw_string());
             NonDeterministicTransitionEffect::transition_into::<ContentState>(self)
         }
     }
@@ -62,6 +66,7 @@ impl From<(ContentState, LineEnding)> for NewlineState {
             content: state.content,
             temp: line_ending.to_string(),
             leading_spaces: 0,
+            options: state.options,
         }
     }
 }
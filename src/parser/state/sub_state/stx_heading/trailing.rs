@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::stx_heading::content::ContentState;
 use crate::parser::state::sub_state::stx_heading::underline::UnderlineState;
 use crate::parser::state::sub_state::stx_heading::{STXHeadingSubState, UnderlineCharacter};
@@ -21,6 +24,7 @@ pub struct TrailingState {
     pub(crate) content: String,
     pub(crate) underline_character: UnderlineCharacter,
     pub(crate) temp: String,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for TrailingState {
@@ -34,7 +38,7 @@ impl Transition for TrailingState {
             }
             character => {
                 self.content.push_str(&*self.temp);
-                self.content.push_str(&*character.to_string());
+                self.content.push_str(&*character.to_raw_string());
                 NonDeterministicTransitionEffect::transition_into::<ContentState>(self)
             }
         }
@@ -56,6 +60,7 @@ impl From<UnderlineState> for TrailingState {
             content: value.content,
             underline_character: value.underline_character,
             temp: value.temp,
+            options: value.options,
         }
     }
 }
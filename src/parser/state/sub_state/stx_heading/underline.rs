@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use crate::parser::character::Character;
 use crate::parser::document::block::Block;
 use crate::parser::effect::NonDeterministicTransitionEffect;
 use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
 use crate::parser::state::sub_state::stx_heading::content::ContentState;
 use crate::parser::state::sub_state::stx_heading::newline::NewlineState;
 use crate::parser::state::sub_state::stx_heading::{STXHeadingSubState, UnderlineCharacter};
@@ -24,6 +27,7 @@ pub struct UnderlineState {
     pub(crate) temp: String,
     pub(crate) underline_character: UnderlineCharacter,
     pub(crate) underline_character_count: usize,
+    pub(crate) options: Rc<ParseOptions>,
 }
 
 impl Transition for UnderlineState {
@@ -45,7 +49,7 @@ impl Transition for UnderlineState {
             },
             character => {
                 self.content.push_str(&*self.temp);
-                self.content.push_str(&*character.to_string());
+                self.content.push_str(&*character.to_raw_string());
                 NonDeterministicTransitionEffect::transition_into::<ContentState>(self)
             }
         }
@@ -68,6 +72,7 @@ impl From<(NewlineState, UnderlineCharacter)> for UnderlineState {
             temp: state.temp,
             underline_character: underline,
             underline_character_count: 0,
+            options: state.options,
         }
     }
 }
@@ -0,0 +1,260 @@
+mod header;
+mod delimiter;
+mod row;
+
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::inline::Inline;
+use crate::parser::document::leaf::{Alignment, Leaf};
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::handler::Handler;
+use crate::parser::state::sub_state::table::delimiter::DelimiterRowState;
+use crate::parser::state::sub_state::table::header::HeaderRowState;
+use crate::parser::state::sub_state::table::row::RowState;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+const PIPE: char = '|';
+
+pub type TableState = Handler<SubState, TableSubState>;
+
+impl From<(Character, Rc<ParseOptions>)> for TableState {
+    fn from(value: (Character, Rc<ParseOptions>)) -> Self {
+        Handler::new(TableSubState::from(value))
+    }
+}
+
+impl From<TableState> for SubState {
+    fn from(value: TableState) -> Self {
+        value.state().into()
+    }
+}
+
+pub enum TableSubState {
+    Header(HeaderRowState),
+    Delimiter(DelimiterRowState),
+    Row(RowState),
+    Complete(State),
+}
+
+impl TableSubState {
+    /// A table claims the block as soon as its delimiter row has been confirmed
+    pub fn claims(&self) -> bool {
+        matches!(self, TableSubState::Row(_))
+    }
+
+    pub fn complete(header: Vec<String>, alignments: Vec<Alignment>, rows: Vec<Vec<String>>) -> NonDeterministicTransitionEffect<TableSubState, Option<Block>> {
+        let cells = |cells: Vec<String>| cells.into_iter()
+            .map(|cell| vec![Inline::Raw(cell)])
+            .collect::<Vec<_>>();
+
+        NonDeterministicTransitionEffect::complete::<Block>(Leaf::Table {
+            alignments,
+            header: cells(header),
+            rows: rows.into_iter().map(cells).collect(),
+        })
+    }
+}
+
+impl Transition for TableSubState {
+    type Effect = NonDeterministicTransitionEffect<TableSubState, Option<Block>>;
+
+    fn transition(self, character: Character) -> Self::Effect {
+        match self {
+            TableSubState::Header(state) => state.transition(character),
+            TableSubState::Delimiter(state) => state.transition(character),
+            TableSubState::Row(state) => state.transition(character),
+            TableSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end_line(self, line_ending: LineEnding) -> Self::Effect {
+        match self {
+            TableSubState::Header(state) => state.end_line(line_ending),
+            TableSubState::Delimiter(state) => state.end_line(line_ending),
+            TableSubState::Row(state) => state.end_line(line_ending),
+            TableSubState::Complete(_) => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        match self {
+            TableSubState::Header(state) => state.end(),
+            TableSubState::Delimiter(state) => state.end(),
+            TableSubState::Row(state) => state.end(),
+            TableSubState::Complete(_) => None,
+        }
+    }
+}
+
+impl From<(Character, Rc<ParseOptions>)> for TableSubState {
+    fn from((character, options): (Character, Rc<ParseOptions>)) -> Self {
+        HeaderRowState::new(character, options).into()
+    }
+}
+
+impl From<TableSubState> for SubState {
+    fn from(value: TableSubState) -> Self {
+        match value {
+            TableSubState::Complete(state) => SubState::IntoSuper(state),
+            state => SubState::Table(
+                TableState::new(state)
+            ),
+        }
+    }
+}
+
+/// Splits a row into its trimmed raw cells, ignoring an optional leading and trailing [PIPE]
+///
+/// An escaped [PIPE] is part of the cell content, even inside code spans, and loses its backslash.
+pub fn split_row(line: &[Character]) -> Vec<String> {
+    let line = trim_blank(line);
+    let mut cells = vec![];
+    let mut cell = String::new();
+
+    for character in line {
+        match character {
+            Character::Unescaped(PIPE) => {
+                cells.push(cell.trim().to_string());
+                cell = String::new();
+            }
+            Character::Escaped(PIPE) => cell.push(PIPE),
+            character => cell.push_str(&character.to_raw_string()),
+        }
+    }
+    cells.push(cell.trim().to_string());
+
+    if let Some(Character::Unescaped(PIPE)) = line.first() {
+        cells.remove(0);
+    }
+
+    if let (Some(Character::Unescaped(PIPE)), false) = (line.last(), cells.is_empty()) {
+        cells.pop();
+    }
+
+    cells
+}
+
+pub fn is_blank(line: &[Character]) -> bool {
+    line.iter().all(Character::is_blank)
+}
+
+fn trim_blank(line: &[Character]) -> &[Character] {
+    let start = line.iter()
+        .position(|character| !character.is_blank())
+        .unwrap_or(line.len());
+    let end = line.iter()
+        .rposition(|character| !character.is_blank())
+        .map_or(start, |index| index + 1);
+
+    &line[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::document::block::Block;
+    use crate::parser::document::heading::HeadingMeta;
+    use crate::parser::document::leaf::Leaf;
+    use crate::parser::document::position::Position;
+    use crate::parser::options::ParseOptions;
+    use crate::parser::Parser;
+
+    #[test]
+    /// Tables (extension)
+    fn test_gfm_example_198() {
+        let parser = Parser::from_reader_with_options("| foo | bar |\n| --- | --- |\n| baz | bim |\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<table>\n<thead>\n<tr>\n<th>foo</th>\n<th>bar</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>baz</td>\n<td>bim</td>\n</tr>\n</tbody>\n</table>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Tables (extension)
+    fn test_gfm_example_199() {
+        let parser = Parser::from_reader_with_options("| abc | defghi |\n:-: | -----------:\nbar | baz\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<table>\n<thead>\n<tr>\n<th align=\"center\">abc</th>\n<th align=\"right\">defghi</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td align=\"center\">bar</td>\n<td align=\"right\">baz</td>\n</tr>\n</tbody>\n</table>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Tables (extension)
+    fn test_gfm_example_200() {
+        let parser = Parser::from_reader_with_options("| f\\|oo  |\n| ------ |\n| b `\\|` az |\n| b **\\|** im |\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<table>\n<thead>\n<tr>\n<th>f|oo</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b <code>|</code> az</td>\n</tr>\n<tr>\n<td>b <strong>|</strong> im</td>\n</tr>\n</tbody>\n</table>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Tables (extension): block quotes are not parsed, the line still ends the table
+    fn test_gfm_example_201() {
+        let parser = Parser::from_reader_with_options("| abc | def |\n| --- | --- |\n| bar | baz |\n> bar\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>bar</td>\n<td>baz</td>\n</tr>\n</tbody>\n</table>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Tables (extension): paragraphs are not parsed, the paragraph after the blank line is dropped
+    fn test_gfm_example_202() {
+        let parser = Parser::from_reader_with_options("| abc | def |\n| --- | --- |\n| bar | baz |\nbar\n\nbar\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>bar</td>\n<td>baz</td>\n</tr>\n<tr>\n<td>bar</td>\n<td></td>\n</tr>\n</tbody>\n</table>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Tables (extension): paragraphs are not parsed, the lines are no table
+    fn test_gfm_example_203() {
+        let parser = Parser::from_reader_with_options("| abc | def |\n| --- |\n| bar |\n".as_bytes(), ParseOptions::gfm());
+        assert!(!parser.parse_to_string().unwrap_or("".into()).contains("<table>"));
+    }
+
+    #[test]
+    /// Tables (extension)
+    fn test_gfm_example_204() {
+        let parser = Parser::from_reader_with_options("| abc | def |\n| --- | --- |\n| bar |\n| bar | baz | boo |\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>bar</td>\n<td></td>\n</tr>\n<tr>\n<td>bar</td>\n<td>baz</td>\n</tr>\n</tbody>\n</table>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Tables (extension)
+    fn test_gfm_example_205() {
+        let parser = Parser::from_reader_with_options("| abc | def |\n| --- | --- |\n".as_bytes(), ParseOptions::gfm());
+        assert_eq!("<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n</table>\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+
+    #[test]
+    /// Tables (extension): lines starting another block end the table
+    fn test_table_interrupted() {
+        let table = "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>x</td>\n</tr>\n</tbody>\n</table>\n";
+
+        for (source, block) in [
+            ("# Heading\n", "<h1>Heading</h1>\n"),
+            ("***\n", "<hr />\n"),
+            ("```\ncode\n```\n", "<pre><code>code\n</code></pre>\n"),
+            ("    code\n", "<pre><code>code\n</code></pre>\n"),
+            ("<div>\n", "<div>\n"),
+        ] {
+            let source = format!("| a |\n| - |\n| x |\n{source}");
+            let parser = Parser::from_reader_with_options(source.as_bytes(), ParseOptions::gfm());
+            assert_eq!(format!("{table}{block}"), parser.parse_to_string().unwrap_or("".into()));
+        }
+    }
+
+    #[test]
+    /// Tables (extension): a heading ending the table at the end of the document keeps its line
+    fn test_table_interrupted_at_end() {
+        let document = Parser::from_reader_with_options("| a |\n| - |\n| x |\n# Heading".as_bytes(), ParseOptions::gfm())
+            .parse()
+            .unwrap();
+
+        assert!(matches!(
+            document.blocks().last(),
+            Some(Block::Leaf(Leaf::AtxHeading { meta: HeadingMeta { position: Some(Position { start_line: 4, end_line: 4 }), .. }, .. }))
+        ));
+    }
+
+    #[test]
+    /// Tables (extension): disabled in strict CommonMark
+    fn test_table_disabled() {
+        let parser = Parser::from_reader("| foo |\n| --- |\n\n***\n".as_bytes());
+        assert_eq!("<hr />\n", parser.parse_to_string().unwrap_or("".into()));
+    }
+}
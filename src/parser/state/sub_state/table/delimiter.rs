@@ -0,0 +1,115 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::leaf::Alignment;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::table;
+use crate::parser::state::sub_state::table::header::HeaderRowState;
+use crate::parser::state::sub_state::table::row::RowState;
+use crate::parser::state::sub_state::table::{TableSubState, PIPE};
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+const DASH: char = '-';
+const COLON: char = ':';
+
+/// # Table: Delimiter row
+/// Delimiter row separating the header from the body and determining the column alignment
+///
+/// ## Transition
+/// * [RowState] on LineEnding when the row contains a [PIPE] and matches the header cell count
+///
+/// ## Completion
+/// * DocumentEnding when the row contains a [PIPE] and matches the header cell count
+///
+/// ## Dismissal
+/// * on characters other than [PIPE], [DASH], [COLON] and whitespace
+/// * LineEnding when the row does not match the header
+pub struct DelimiterRowState {
+    pub(crate) header: Vec<String>,
+    pub(crate) line: Vec<Character>,
+    pub(crate) options: Rc<ParseOptions>,
+}
+
+impl DelimiterRowState {
+    pub fn alignments(&self) -> Option<Vec<Alignment>> {
+        if !self.line.iter().any(|character| matches!(character, Character::Unescaped(PIPE))) {
+            return None;
+        }
+
+        let alignments = table::split_row(&self.line)
+            .iter()
+            .map(|cell| alignment(cell))
+            .collect::<Option<Vec<Alignment>>>()?;
+
+        Some(alignments)
+            .filter(|alignments| alignments.len() == self.header.len())
+    }
+}
+
+impl Transition for DelimiterRowState {
+    type Effect = NonDeterministicTransitionEffect<TableSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        match character {
+            Character::Unescaped(PIPE | DASH | COLON | unicode::SPACE | unicode::TAB) => {
+                self.line.push(character);
+                NonDeterministicTransitionEffect::pass(self)
+            }
+            _ => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        match self.alignments() {
+            Some(alignments) => NonDeterministicTransitionEffect::transition_into::<RowState>((
+                self,
+                alignments,
+            )),
+            None => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        let alignments = self.alignments()?;
+
+        TableSubState::complete(self.header, alignments, vec![])
+            .end()
+    }
+}
+
+impl From<HeaderRowState> for DelimiterRowState {
+    fn from(value: HeaderRowState) -> Self {
+        Self {
+            header: table::split_row(&value.line),
+            line: vec![],
+            options: value.options,
+        }
+    }
+}
+
+impl From<DelimiterRowState> for TableSubState {
+    fn from(value: DelimiterRowState) -> Self {
+        TableSubState::Delimiter(value)
+    }
+}
+
+fn alignment(cell: &str) -> Option<Alignment> {
+    let left = cell.starts_with(COLON);
+    let right = cell.ends_with(COLON) && cell.len() > 1;
+    let dashes = cell.trim_start_matches(COLON).trim_end_matches(COLON);
+
+    if dashes.is_empty() || !dashes.chars().all(|character| character == DASH) {
+        return None;
+    }
+
+    Some(match (left, right) {
+        (true, true) => Alignment::Center,
+        (true, false) => Alignment::Left,
+        (false, true) => Alignment::Right,
+        (false, false) => Alignment::None,
+    })
+}
@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::table::delimiter::DelimiterRowState;
+use crate::parser::state::sub_state::table::TableSubState;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Table: Header row
+/// Potential header row of a table
+///
+/// ## Transition
+/// * [DelimiterRowState] on LineEnding
+///
+/// ## Dismissal
+/// * DocumentEnding
+pub struct HeaderRowState {
+    pub(crate) line: Vec<Character>,
+    pub(crate) options: Rc<ParseOptions>,
+}
+
+impl HeaderRowState {
+    pub fn new(character: Character, options: Rc<ParseOptions>) -> Self {
+        Self {
+            line: vec![character],
+            options,
+        }
+    }
+}
+
+impl Transition for HeaderRowState {
+    type Effect = NonDeterministicTransitionEffect<TableSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push(character);
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        NonDeterministicTransitionEffect::transition_into::<DelimiterRowState>(self)
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        None
+    }
+}
+
+impl From<HeaderRowState> for TableSubState {
+    fn from(value: HeaderRowState) -> Self {
+        TableSubState::Header(value)
+    }
+}
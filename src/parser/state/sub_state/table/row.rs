@@ -0,0 +1,102 @@
+use std::rc::Rc;
+
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::leaf::Alignment;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::line_ending::LineEnding;
+use crate::parser::options::ParseOptions;
+use crate::parser::state::sub_state::interruption;
+use crate::parser::state::sub_state::table;
+use crate::parser::state::sub_state::table::delimiter::DelimiterRowState;
+use crate::parser::state::sub_state::table::TableSubState;
+use crate::parser::state::State;
+use crate::parser::transition::{Transition, TransitionEffect};
+
+/// # Table: Body row
+/// Body rows of a table, padded or truncated to the header cell count
+///
+/// ## Completion
+/// * on the blank following the opening sequence of an ATX heading, the line is handed back
+/// * LineEnding on a blank line
+/// * LineEnding on a line starting another block or a block quote, the line is handed back
+/// * DocumentEnding
+pub struct RowState {
+    pub(crate) header: Vec<String>,
+    pub(crate) alignments: Vec<Alignment>,
+    pub(crate) rows: Vec<Vec<String>>,
+    pub(crate) line: Vec<Character>,
+    pub(crate) options: Rc<ParseOptions>,
+}
+
+impl RowState {
+    fn push_row(&mut self) {
+        let mut cells = table::split_row(&self.line);
+        cells.resize(self.header.len(), String::new());
+
+        self.rows.push(cells);
+        self.line.clear();
+    }
+
+    /// Completes the table, handing the current line to `state`
+    fn hand_over(self, state: State) -> NonDeterministicTransitionEffect<TableSubState, Option<Block>> {
+        let (_, table) = TableSubState::complete(self.header, self.alignments, self.rows).content();
+
+        NonDeterministicTransitionEffect::new(TableSubState::Complete(state), table)
+    }
+}
+
+impl Transition for RowState {
+    type Effect = NonDeterministicTransitionEffect<TableSubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        self.line.push(character);
+
+        if let Some(state) = interruption::heading(&self.line, &self.options) {
+            return self.hand_over(state);
+        }
+
+        NonDeterministicTransitionEffect::pass(self)
+    }
+
+    fn end_line(mut self, line_ending: LineEnding) -> Self::Effect {
+        if table::is_blank(&self.line) {
+            return TableSubState::complete(self.header, self.alignments, self.rows);
+        }
+
+        match interruption::line(&self.line, line_ending, &self.options) {
+            Some(state) => self.hand_over(state),
+            None => {
+                self.push_row();
+                NonDeterministicTransitionEffect::pass(self)
+            }
+        }
+    }
+
+    fn end(mut self) -> <Self::Effect as TransitionEffect>::Outcome {
+        if !table::is_blank(&self.line) {
+            self.push_row();
+        }
+
+        TableSubState::complete(self.header, self.alignments, self.rows)
+            .end()
+    }
+}
+
+impl From<(DelimiterRowState, Vec<Alignment>)> for RowState {
+    fn from((state, alignments): (DelimiterRowState, Vec<Alignment>)) -> Self {
+        Self {
+            header: state.header,
+            alignments,
+            rows: vec![],
+            line: vec![],
+            options: state.options,
+        }
+    }
+}
+
+impl From<RowState> for TableSubState {
+    fn from(value: RowState) -> Self {
+        TableSubState::Row(value)
+    }
+}
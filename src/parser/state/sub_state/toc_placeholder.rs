@@ -0,0 +1,85 @@
+use crate::error::Error;
+use crate::parser::character::Character;
+use crate::parser::document::block::Block;
+use crate::parser::document::leaf::Leaf;
+use crate::parser::effect::NonDeterministicTransitionEffect;
+use crate::parser::state::LineEnding;
+use crate::parser::state::sub_state::SubState;
+use crate::parser::transition::{Transition, TransitionEffect};
+use crate::unicode;
+
+pub const PLACEHOLDERS: [&str; 2] = ["[[_TOC_]]", "[TOC]"];
+
+/// # Table of contents placeholder
+/// `[[_TOC_]]` or `[TOC]` alone on a line, followed by optional whitespace
+///
+/// ## Completion
+/// * LineEnding or DocumentEnding after a complete placeholder
+///
+/// ## Dismissal
+/// * any character not continuing a placeholder
+/// * any character other than whitespace after a complete placeholder
+pub struct TocPlaceholderState {
+    marker: String,
+    trailing: bool,
+}
+
+impl TocPlaceholderState {
+    fn is_complete(&self) -> bool {
+        PLACEHOLDERS.contains(&self.marker.as_str())
+    }
+}
+
+impl Transition for TocPlaceholderState {
+    type Effect = NonDeterministicTransitionEffect<SubState, Option<Block>>;
+
+    fn transition(mut self, character: Character) -> Self::Effect {
+        match character {
+            Character::Unescaped(unicode::SPACE | unicode::TAB) if self.is_complete() => {
+                self.trailing = true;
+                NonDeterministicTransitionEffect::pass(self)
+            }
+            Character::Unescaped(character) if !self.trailing => {
+                self.marker.push(character);
+
+                match PLACEHOLDERS.iter().any(|placeholder| placeholder.starts_with(&self.marker)) {
+                    true => NonDeterministicTransitionEffect::pass(self),
+                    false => NonDeterministicTransitionEffect::dismiss(),
+                }
+            }
+            _ => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end_line(self, _: LineEnding) -> Self::Effect {
+        match self.is_complete() {
+            true => NonDeterministicTransitionEffect::complete::<Block>(Leaf::TableOfContents),
+            false => NonDeterministicTransitionEffect::dismiss(),
+        }
+    }
+
+    fn end(self) -> <Self::Effect as TransitionEffect>::Outcome {
+        self.is_complete()
+            .then(|| Leaf::TableOfContents.into())
+    }
+}
+
+impl TryFrom<Character> for TocPlaceholderState {
+    type Error = Error;
+
+    fn try_from(value: Character) -> Result<Self, Self::Error> {
+        match value {
+            Character::Unescaped('[') => Ok(Self {
+                marker: "[".to_string(),
+                trailing: false,
+            }),
+            _ => Err(Error::Conversion),
+        }
+    }
+}
+
+impl From<TocPlaceholderState> for SubState {
+    fn from(value: TocPlaceholderState) -> Self {
+        SubState::TocPlaceholder(value)
+    }
+}
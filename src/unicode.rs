@@ -28,4 +28,11 @@ pub fn escapable(character: &char) -> bool {
         ',', '-', '.', '/', ':', ';', '<', '=', '>', '?', '@',
         '[', '\\', ']', '^', '_', '`', '{', '|', '}', '~',
     ].contains(character)
-}
\ No newline at end of file
+}
+
+/// ASCII punctuation and, approximating the Unicode `P` and `S` categories, every other
+/// character that is neither alphanumeric nor whitespace
+pub fn is_punctuation(character: char) -> bool {
+    character.is_ascii_punctuation()
+        || (!character.is_ascii() && !character.is_alphanumeric() && !character.is_whitespace())
+}